@@ -0,0 +1,26 @@
+#[cfg(feature = "header")]
+extern crate cbindgen;
+
+/// Regenerate the checked in C header when built with the `header` feature,
+/// so ordinary builds leave the source tree alone
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    #[cfg(feature = "header")]
+    write_header();
+}
+
+/// Write the C header for the functions exported by the library
+#[cfg(feature = "header")]
+fn write_header() {
+    use std::env;
+    use std::path::Path;
+
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let config = cbindgen::Config::from_file(Path::new(&crate_dir).join("cbindgen.toml"))
+        .expect("cbindgen.toml is readable");
+    cbindgen::generate_with_config(&crate_dir, config)
+        .expect("The library can be read by cbindgen")
+        .write_to_file(Path::new(&crate_dir).join("include").join("thyme.h"));
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+}
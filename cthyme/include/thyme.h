@@ -0,0 +1,179 @@
+/* Generated by cbindgen from cthyme/src/lib.rs. Do not edit. */
+
+/* A null game is answered with THYME_RESULT_NULL_GAME, or -1 or 0 where a
+ * number is returned. Out-pointers may be null, in which case nothing is
+ * written to them. */
+
+#ifndef THYME_H
+#define THYME_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Version of the API, raised whenever a function or type changes
+#define THYME_API_VERSION 1
+
+// Number of stacks on the board
+#define THYME_STACK_COUNT 9
+
+// A hand which can be played from the board
+typedef enum ThymeHand {
+  THYME_HAND_STRAIGHT_FLUSH = 0,
+  THYME_HAND_FOUR_OF_A_KIND = 1,
+  THYME_HAND_FLUSH = 2,
+  THYME_HAND_FULL_HOUSE = 3,
+  THYME_HAND_FIVE_CARD_STRAIGHT = 4,
+  THYME_HAND_THREE_OF_A_KIND = 5,
+  THYME_HAND_THREE_CARD_STRAIGHT = 6,
+  THYME_HAND_PAIR = 7,
+  THYME_HAND_TRASH = 8,
+} ThymeHand;
+
+// Outcome of checking or playing a selection
+typedef enum ThymeResult {
+  THYME_RESULT_OK = 0,
+  // The move cannot be played given the current board
+  THYME_RESULT_INVALID_MOVE = 1,
+  // The move cannot be played because it uses only a single row
+  THYME_RESULT_NEED_MULTIPLE_ROWS = 2,
+  // The board is empty
+  THYME_RESULT_NO_MOVES_REMAIN = 3,
+  // No discards remain
+  THYME_RESULT_NO_DISCARDS_REMAIN = 4,
+  // Two pair is not a sage
+  THYME_RESULT_TWO_PAIR_IS_INVALID = 5,
+  // The cards would be a straight if not for a missing value
+  THYME_RESULT_STRAIGHT_HAS_GAP = 6,
+  // A flush must be made of five cards
+  THYME_RESULT_FLUSH_NEEDS_FIVE_CARDS = 7,
+  // The move cannot be played given the current hand
+  THYME_RESULT_INVALID_HAND = 8,
+  // The time limit has passed
+  THYME_RESULT_OUT_OF_TIME = 9,
+  // The game has already been won or lost
+  THYME_RESULT_GAME_FINISHED = 10,
+  // The selection is empty or selects a stack which does not exist
+  THYME_RESULT_INVALID_SELECTION = 11,
+  // The game is null
+  THYME_RESULT_NULL_GAME = 12,
+} ThymeResult;
+
+// Progress of a game
+typedef enum ThymeStatus {
+  // Moves can still be played
+  THYME_STATUS_IN_PROGRESS = 0,
+  // Every card was cleared from the board
+  THYME_STATUS_WON = 1,
+  // No moves remain or the time ran out with cards left on the board
+  THYME_STATUS_LOST = 2,
+} ThymeStatus;
+
+// A game played on a board, scored with the standard scorer
+typedef struct ThymeGame ThymeGame;
+
+// Create a game on a board dealt from a seed, so the same board can be
+// dealt again. The game must be released with `thyme_game_free`.
+struct ThymeGame *thyme_game_new(uint64_t seed);
+
+// Release a game. Null is ignored.
+//
+// # Safety
+//
+// `game` must be null or a game created by `thyme_game_new` which has not
+// already been released.
+void thyme_game_free(struct ThymeGame *game);
+
+// Number of the card on top of a stack, or -1 if the stack is empty or
+// does not exist or the game is null
+//
+// # Safety
+//
+// `game` must be null or a game created by `thyme_game_new` and not yet
+// released.
+int32_t thyme_game_top_card(const struct ThymeGame *game, size_t stack);
+
+// Number of cards left in a stack, or 0 if the stack does not exist or the
+// game is null
+//
+// # Safety
+//
+// `game` must be null or a game created by `thyme_game_new` and not yet
+// released.
+size_t thyme_game_count_cards(const struct ThymeGame *game, size_t stack);
+
+// Number of cards left on the board, or 0 if the game is null
+//
+// # Safety
+//
+// `game` must be null or a game created by `thyme_game_new` and not yet
+// released.
+size_t thyme_game_count_all_cards(const struct ThymeGame *game);
+
+// Number of the lucky card, or -1 if the game is null
+//
+// # Safety
+//
+// `game` must be null or a game created by `thyme_game_new` and not yet
+// released.
+int32_t thyme_game_lucky_card(const struct ThymeGame *game);
+
+// Find the highest ranked hand the top cards of a selection of stacks make,
+// writing it to `hand` if they make one, unless it is null
+//
+// # Safety
+//
+// `game` must be null or a game created by `thyme_game_new` and not yet
+// released, and `hand` must be null or point to writable memory for a
+// `ThymeHand`.
+enum ThymeResult thyme_game_check(const struct ThymeGame *game,
+                                  uint16_t selection,
+                                  enum ThymeHand *hand);
+
+// Play a hand from the top cards of a selection of stacks, adding it to the
+// score and writing the points it scored to `points` unless it is null.
+// `hand` is the number of a `ThymeHand`, and any other number is answered
+// with `THYME_RESULT_INVALID_HAND`.
+//
+// # Safety
+//
+// `game` must be null or a game created by `thyme_game_new` and not yet
+// released, and `points` must be null or point to writable memory for an
+// `int32_t`.
+enum ThymeResult thyme_game_play(struct ThymeGame *game,
+                                 int32_t hand,
+                                 uint16_t selection,
+                                 int32_t *points);
+
+// Points scored so far, or 0 if the game is null
+//
+// # Safety
+//
+// `game` must be null or a game created by `thyme_game_new` and not yet
+// released.
+int32_t thyme_game_score(const struct ThymeGame *game);
+
+// Number of discards which can still be played, or 0 if the game is null
+//
+// # Safety
+//
+// `game` must be null or a game created by `thyme_game_new` and not yet
+// released.
+int32_t thyme_game_discards_allowed(const struct ThymeGame *game);
+
+// Whether the game is in progress, won or lost. A null game is lost, as no
+// moves can be played on it.
+//
+// # Safety
+//
+// `game` must be null or a game created by `thyme_game_new` and not yet
+// released.
+enum ThymeStatus thyme_game_status(const struct ThymeGame *game);
+
+// A message describing the number of a `ThymeResult`, which lives as long as
+// the program and must not be freed, or null if no result has the number
+const char *thyme_result_message(int32_t result);
+
+#endif  /* THYME_H */
@@ -0,0 +1,410 @@
+//! A C API for libthyme, built as the `cthyme` library with the header
+//! `include/thyme.h`.
+//!
+//! Games are opaque handles created with `thyme_game_new` and released with
+//! `thyme_game_free`. Stacks are numbered 0 to 8 in the order they are dealt,
+//! from the top left to the bottom right, and a selection of stacks is a
+//! bitmask where bit `i` selects stack `i`. Cards are numbered
+//! `suit*13 + value`, counting from spades and two. Functions given a null
+//! game return a placeholder such as -1 or `THYME_RESULT_NULL_GAME` rather
+//! than reading it, and results are only written to out-pointers which are
+//! not null.
+
+extern crate cards;
+extern crate libthyme;
+
+use libthyme::board::{Board,Position,POSITIONS};
+use libthyme::game::{Game,GameStatus,MoveError,MoveType};
+use libthyme::score::StandardScorer;
+use std::convert::TryFrom;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::OnceLock;
+
+/// Version of the API, raised whenever a function or type changes
+pub const THYME_API_VERSION: u32 = 1;
+
+/// Number of stacks on the board
+pub const THYME_STACK_COUNT: usize = 9;
+
+/// A game played on a board, scored with the standard scorer
+pub struct ThymeGame {
+    game: Game<StandardScorer>,
+}
+
+/// A hand which can be played from the board
+#[repr(C)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ThymeHand {
+    StraightFlush = 0,
+    FourOfAKind = 1,
+    Flush = 2,
+    FullHouse = 3,
+    FiveCardStraight = 4,
+    ThreeOfAKind = 5,
+    ThreeCardStraight = 6,
+    Pair = 7,
+    Trash = 8,
+}
+
+/// Progress of a game
+#[repr(C)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ThymeStatus {
+    /// Moves can still be played
+    InProgress = 0,
+    /// Every card was cleared from the board
+    Won = 1,
+    /// No moves remain or the time ran out with cards left on the board
+    Lost = 2,
+}
+
+/// Outcome of checking or playing a selection
+#[repr(C)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ThymeResult {
+    Ok = 0,
+    /// The move cannot be played given the current board
+    InvalidMove = 1,
+    /// The move cannot be played because it uses only a single row
+    NeedMultipleRows = 2,
+    /// The board is empty
+    NoMovesRemain = 3,
+    /// No discards remain
+    NoDiscardsRemain = 4,
+    /// Two pair is not a sage
+    TwoPairIsInvalid = 5,
+    /// The cards would be a straight if not for a missing value
+    StraightHasGap = 6,
+    /// A flush must be made of five cards
+    FlushNeedsFiveCards = 7,
+    /// The move cannot be played given the current hand
+    InvalidHand = 8,
+    /// The time limit has passed
+    OutOfTime = 9,
+    /// The game has already been won or lost
+    GameFinished = 10,
+    /// The selection is empty or selects a stack which does not exist
+    InvalidSelection = 11,
+    /// The game is null
+    NullGame = 12,
+}
+
+/// Every hand, in the order they are numbered
+const HANDS: [ThymeHand; 9] = [
+    ThymeHand::StraightFlush,
+    ThymeHand::FourOfAKind,
+    ThymeHand::Flush,
+    ThymeHand::FullHouse,
+    ThymeHand::FiveCardStraight,
+    ThymeHand::ThreeOfAKind,
+    ThymeHand::ThreeCardStraight,
+    ThymeHand::Pair,
+    ThymeHand::Trash,
+];
+
+/// Every result, in the order they are numbered
+const RESULTS: [ThymeResult; 13] = [
+    ThymeResult::Ok,
+    ThymeResult::InvalidMove,
+    ThymeResult::NeedMultipleRows,
+    ThymeResult::NoMovesRemain,
+    ThymeResult::NoDiscardsRemain,
+    ThymeResult::TwoPairIsInvalid,
+    ThymeResult::StraightHasGap,
+    ThymeResult::FlushNeedsFiveCards,
+    ThymeResult::InvalidHand,
+    ThymeResult::OutOfTime,
+    ThymeResult::GameFinished,
+    ThymeResult::InvalidSelection,
+    ThymeResult::NullGame,
+];
+
+/// Messages describing each result, indexed by result
+static MESSAGES: OnceLock<Vec<CString>> = OnceLock::new();
+
+/// Create a game on a board dealt from a seed, so the same board can be
+/// dealt again. The game must be released with `thyme_game_free`.
+#[no_mangle]
+pub extern "C" fn thyme_game_new(seed: u64) -> *mut ThymeGame {
+    let game = Game::with_board(Board::with_seed(seed));
+    return Box::into_raw(Box::new(ThymeGame { game: game }))
+}
+
+/// Release a game. Null is ignored.
+///
+/// # Safety
+///
+/// `game` must be null or a game created by `thyme_game_new` which has not
+/// already been released.
+#[no_mangle]
+pub unsafe extern "C" fn thyme_game_free(game: *mut ThymeGame) {
+    if !game.is_null() {
+        drop(Box::from_raw(game));
+    }
+}
+
+/// Number of the card on top of a stack, or -1 if the stack is empty or
+/// does not exist or the game is null
+///
+/// # Safety
+///
+/// `game` must be null or a game created by `thyme_game_new` and not yet
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn thyme_game_top_card(game: *const ThymeGame, stack: usize) -> i32 {
+    let board = match game.as_ref() {
+        Some(game) => &game.game.board,
+        None => return -1,
+    };
+    return match POSITIONS.get(stack).and_then(|p| board.top(*p)) {
+        Some(card) => card_number(card),
+        None => -1,
+    }
+}
+
+/// Number of cards left in a stack, or 0 if the stack does not exist or the
+/// game is null
+///
+/// # Safety
+///
+/// `game` must be null or a game created by `thyme_game_new` and not yet
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn thyme_game_count_cards(game: *const ThymeGame, stack: usize) -> usize {
+    let board = match game.as_ref() {
+        Some(game) => &game.game.board,
+        None => return 0,
+    };
+    return POSITIONS.get(stack).map(|p| board.count_cards(*p)).unwrap_or(0)
+}
+
+/// Number of cards left on the board, or 0 if the game is null
+///
+/// # Safety
+///
+/// `game` must be null or a game created by `thyme_game_new` and not yet
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn thyme_game_count_all_cards(game: *const ThymeGame) -> usize {
+    return game.as_ref().map(|game| game.game.board.count_all_cards()).unwrap_or(0)
+}
+
+/// Number of the lucky card, or -1 if the game is null
+///
+/// # Safety
+///
+/// `game` must be null or a game created by `thyme_game_new` and not yet
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn thyme_game_lucky_card(game: *const ThymeGame) -> i32 {
+    return game.as_ref().map(|game| card_number(game.game.board.lucky_card)).unwrap_or(-1)
+}
+
+/// Find the highest ranked hand the top cards of a selection of stacks make,
+/// writing it to `hand` if they make one, unless it is null
+///
+/// # Safety
+///
+/// `game` must be null or a game created by `thyme_game_new` and not yet
+/// released, and `hand` must be null or point to writable memory for a
+/// `ThymeHand`.
+#[no_mangle]
+pub unsafe extern "C" fn thyme_game_check(game: *const ThymeGame, selection: u16,
+                                          hand: *mut ThymeHand) -> ThymeResult {
+    let game = match game.as_ref() {
+        Some(game) => &game.game,
+        None => return ThymeResult::NullGame,
+    };
+    let positions = match read_selection(selection) {
+        Some(positions) => positions,
+        None => return ThymeResult::InvalidSelection,
+    };
+    return match game.check(&positions) {
+        Ok(move_type) => {
+            if !hand.is_null() {
+                *hand = ThymeHand::from(move_type);
+            }
+            ThymeResult::Ok
+        },
+        Err(error) => ThymeResult::from(error),
+    }
+}
+
+/// Play a hand from the top cards of a selection of stacks, adding it to the
+/// score and writing the points it scored to `points` unless it is null.
+/// `hand` is the number of a `ThymeHand`, and any other number is answered
+/// with `THYME_RESULT_INVALID_HAND`.
+///
+/// # Safety
+///
+/// `game` must be null or a game created by `thyme_game_new` and not yet
+/// released, and `points` must be null or point to writable memory for an
+/// `int32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn thyme_game_play(game: *mut ThymeGame, hand: i32, selection: u16,
+                                         points: *mut i32) -> ThymeResult {
+    let game = match game.as_mut() {
+        Some(game) => &mut game.game,
+        None => return ThymeResult::NullGame,
+    };
+    let hand = match usize::try_from(hand).ok().and_then(|i| HANDS.get(i)) {
+        Some(hand) => *hand,
+        None => return ThymeResult::InvalidHand,
+    };
+    let positions = match read_selection(selection) {
+        Some(positions) => positions,
+        None => return ThymeResult::InvalidSelection,
+    };
+    let score = game.score();
+    return match game.play(hand.into(), &positions) {
+        Ok(_) => {
+            if !points.is_null() {
+                *points = game.score() - score;
+            }
+            ThymeResult::Ok
+        },
+        Err(error) => ThymeResult::from(error),
+    }
+}
+
+/// Points scored so far, or 0 if the game is null
+///
+/// # Safety
+///
+/// `game` must be null or a game created by `thyme_game_new` and not yet
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn thyme_game_score(game: *const ThymeGame) -> i32 {
+    return game.as_ref().map(|game| game.game.score()).unwrap_or(0)
+}
+
+/// Number of discards which can still be played, or 0 if the game is null
+///
+/// # Safety
+///
+/// `game` must be null or a game created by `thyme_game_new` and not yet
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn thyme_game_discards_allowed(game: *const ThymeGame) -> i32 {
+    return game.as_ref().map(|game| game.game.discards_allowed).unwrap_or(0)
+}
+
+/// Whether the game is in progress, won or lost. A null game is lost, as no
+/// moves can be played on it.
+///
+/// # Safety
+///
+/// `game` must be null or a game created by `thyme_game_new` and not yet
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn thyme_game_status(game: *const ThymeGame) -> ThymeStatus {
+    return match game.as_ref().map(|game| game.game.status) {
+        Some(GameStatus::InProgress) => ThymeStatus::InProgress,
+        Some(GameStatus::Won) => ThymeStatus::Won,
+        Some(GameStatus::Lost { .. }) | None => ThymeStatus::Lost,
+    }
+}
+
+/// A message describing the number of a `ThymeResult`, which lives as long as
+/// the program and must not be freed, or null if no result has the number
+#[no_mangle]
+pub extern "C" fn thyme_result_message(result: i32) -> *const c_char {
+    let messages = MESSAGES.get_or_init(|| RESULTS.iter().map(|result| {
+        let message = match move_error(*result) {
+            Some(error) => error.to_string(),
+            None => match *result {
+                ThymeResult::Ok => "OK".to_string(),
+                ThymeResult::NullGame => "The game is null".to_string(),
+                _ => "The selection is empty or selects a stack which does not exist".to_string(),
+            },
+        };
+        CString::new(message).unwrap()
+    }).collect());
+    return usize::try_from(result).ok()
+        .and_then(|i| messages.get(i))
+        .map(|message| message.as_ptr())
+        .unwrap_or(ptr::null())
+}
+
+/// Positions selected by a bitmask, or None if it selects none or a
+/// position which does not exist
+fn read_selection(selection: u16) -> Option<Vec<Position>> {
+    if selection == 0 || selection >> POSITIONS.len() != 0 {
+        return None
+    }
+    return Some(POSITIONS.iter().enumerate()
+        .filter(|&(i, _)| selection & (1 << i) != 0)
+        .map(|(_, p)| *p)
+        .collect())
+}
+
+fn card_number(card: cards::card::Card) -> i32 {
+    return card.suit as i32*13 + card.value as i32
+}
+
+fn move_error(result: ThymeResult) -> Option<MoveError> {
+    return match result {
+        ThymeResult::InvalidMove => Some(MoveError::InvalidMove),
+        ThymeResult::NeedMultipleRows => Some(MoveError::NeedMultipleRows),
+        ThymeResult::NoMovesRemain => Some(MoveError::NoMovesRemain),
+        ThymeResult::NoDiscardsRemain => Some(MoveError::NoDiscardsRemain),
+        ThymeResult::TwoPairIsInvalid => Some(MoveError::TwoPairIsInvalid),
+        ThymeResult::StraightHasGap => Some(MoveError::StraightHasGap),
+        ThymeResult::FlushNeedsFiveCards => Some(MoveError::FlushNeedsFiveCards),
+        ThymeResult::InvalidHand => Some(MoveError::InvalidHand),
+        ThymeResult::OutOfTime => Some(MoveError::OutOfTime),
+        ThymeResult::GameFinished => Some(MoveError::GameFinished),
+        ThymeResult::Ok | ThymeResult::InvalidSelection | ThymeResult::NullGame => None,
+    }
+}
+
+impl From<MoveError> for ThymeResult {
+    fn from(error: MoveError) -> ThymeResult {
+        return match error {
+            MoveError::InvalidMove => ThymeResult::InvalidMove,
+            MoveError::NeedMultipleRows => ThymeResult::NeedMultipleRows,
+            MoveError::NoMovesRemain => ThymeResult::NoMovesRemain,
+            MoveError::NoDiscardsRemain => ThymeResult::NoDiscardsRemain,
+            MoveError::TwoPairIsInvalid => ThymeResult::TwoPairIsInvalid,
+            MoveError::StraightHasGap => ThymeResult::StraightHasGap,
+            MoveError::FlushNeedsFiveCards => ThymeResult::FlushNeedsFiveCards,
+            MoveError::InvalidHand => ThymeResult::InvalidHand,
+            MoveError::OutOfTime => ThymeResult::OutOfTime,
+            MoveError::GameFinished => ThymeResult::GameFinished,
+        }
+    }
+}
+
+impl From<MoveType> for ThymeHand {
+    fn from(hand: MoveType) -> ThymeHand {
+        return match hand {
+            MoveType::StraightFlush => ThymeHand::StraightFlush,
+            MoveType::FourOfAKind => ThymeHand::FourOfAKind,
+            MoveType::Flush => ThymeHand::Flush,
+            MoveType::FullHouse => ThymeHand::FullHouse,
+            MoveType::FiveCardStraight => ThymeHand::FiveCardStraight,
+            MoveType::ThreeOfAKind => ThymeHand::ThreeOfAKind,
+            MoveType::ThreeCardStraight => ThymeHand::ThreeCardStraight,
+            MoveType::Pair => ThymeHand::Pair,
+            MoveType::Trash => ThymeHand::Trash,
+        }
+    }
+}
+
+impl From<ThymeHand> for MoveType {
+    fn from(hand: ThymeHand) -> MoveType {
+        return match hand {
+            ThymeHand::StraightFlush => MoveType::StraightFlush,
+            ThymeHand::FourOfAKind => MoveType::FourOfAKind,
+            ThymeHand::Flush => MoveType::Flush,
+            ThymeHand::FullHouse => MoveType::FullHouse,
+            ThymeHand::FiveCardStraight => MoveType::FiveCardStraight,
+            ThymeHand::ThreeOfAKind => MoveType::ThreeOfAKind,
+            ThymeHand::ThreeCardStraight => MoveType::ThreeCardStraight,
+            ThymeHand::Pair => MoveType::Pair,
+            ThymeHand::Trash => MoveType::Trash,
+        }
+    }
+}
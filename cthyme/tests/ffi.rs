@@ -0,0 +1,137 @@
+extern crate cthyme;
+extern crate libthyme;
+
+use cthyme::*;
+use libthyme::board::{Board,Position,POSITIONS};
+use libthyme::game::{Game,MoveError,MoveType};
+use libthyme::score::StandardScorer;
+use std::ffi::CStr;
+use std::ptr;
+
+fn selection(positions: &Vec<Position>) -> u16 {
+    return POSITIONS.iter().enumerate()
+        .filter(|&(_, p)| positions.contains(p))
+        .map(|(i, _)| 1 << i)
+        .sum()
+}
+
+#[test]
+fn deals_board_from_seed() {
+    let board = Board::with_seed(7);
+    unsafe {
+        let game = thyme_game_new(7);
+        for (i, position) in POSITIONS.iter().enumerate() {
+            let card = board.top(*position).unwrap();
+            assert_eq!(thyme_game_top_card(game, i), card.suit as i32*13 + card.value as i32);
+            assert_eq!(thyme_game_count_cards(game, i), board.count_cards(*position));
+        }
+        assert_eq!(thyme_game_top_card(game, THYME_STACK_COUNT), -1);
+        assert_eq!(thyme_game_count_cards(game, THYME_STACK_COUNT), 0);
+        assert_eq!(thyme_game_count_all_cards(game), board.count_all_cards());
+        let lucky = board.lucky_card;
+        assert_eq!(thyme_game_lucky_card(game), lucky.suit as i32*13 + lucky.value as i32);
+        assert_eq!(thyme_game_score(game), 0);
+        assert_eq!(thyme_game_status(game), ThymeStatus::InProgress);
+        thyme_game_free(game);
+    }
+}
+
+#[test]
+fn checks_and_plays_selection() {
+    let expected = Game::<StandardScorer>::with_board(Board::with_seed(7));
+    let (hand, positions) = expected.available_moves().into_iter()
+        .find(|&(hand, _)| hand != MoveType::Trash)
+        .unwrap();
+    let points = expected.check_score(hand, &positions).unwrap().total();
+    unsafe {
+        let game = thyme_game_new(7);
+        let mut checked = ThymeHand::Trash;
+        let result = thyme_game_check(game, selection(&positions), &mut checked);
+        assert_eq!(result, ThymeResult::Ok);
+        assert_eq!(MoveType::from(checked), expected.check(&positions).unwrap());
+        assert_eq!(thyme_game_check(game, selection(&positions), ptr::null_mut()), ThymeResult::Ok);
+        let mut scored = 0;
+        let result = thyme_game_play(game, ThymeHand::from(hand) as i32, selection(&positions), &mut scored);
+        assert_eq!(result, ThymeResult::Ok);
+        assert_eq!(scored, points);
+        assert_eq!(thyme_game_score(game), points);
+        assert_eq!(thyme_game_count_all_cards(game), 51 - positions.len());
+        thyme_game_free(game);
+    }
+}
+
+#[test]
+fn rejects_invalid_moves() {
+    let expected = Game::<StandardScorer>::with_board(Board::with_seed(7));
+    let positions = vec![POSITIONS[0], POSITIONS[1]];
+    let error = expected.check(&positions).err();
+    unsafe {
+        let game = thyme_game_new(7);
+        let mut hand = ThymeHand::Trash;
+        let result = thyme_game_check(game, selection(&positions), &mut hand);
+        assert_eq!(result, error.map(ThymeResult::from).unwrap_or(ThymeResult::Ok));
+        let result = thyme_game_play(game, ThymeHand::Pair as i32, 0, ptr::null_mut());
+        assert_eq!(result, ThymeResult::InvalidSelection);
+        let result = thyme_game_play(game, ThymeHand::Pair as i32, 1 << THYME_STACK_COUNT, ptr::null_mut());
+        assert_eq!(result, ThymeResult::InvalidSelection);
+        let result = thyme_game_play(game, ThymeHand::StraightFlush as i32, 1, ptr::null_mut());
+        assert!(result != ThymeResult::Ok);
+        let result = thyme_game_play(game, -1, 1, ptr::null_mut());
+        assert_eq!(result, ThymeResult::InvalidHand);
+        let result = thyme_game_play(game, 9, 1, ptr::null_mut());
+        assert_eq!(result, ThymeResult::InvalidHand);
+        assert_eq!(thyme_game_score(game), 0);
+        thyme_game_free(game);
+        thyme_game_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn ignores_null_game() {
+    let game = ptr::null_mut();
+    unsafe {
+        let mut hand = ThymeHand::Trash;
+        assert_eq!(thyme_game_check(game, 1, &mut hand), ThymeResult::NullGame);
+        assert_eq!(thyme_game_play(game, ThymeHand::Trash as i32, 1, ptr::null_mut()), ThymeResult::NullGame);
+        assert_eq!(thyme_game_top_card(game, 0), -1);
+        assert_eq!(thyme_game_count_cards(game, 0), 0);
+        assert_eq!(thyme_game_count_all_cards(game), 0);
+        assert_eq!(thyme_game_lucky_card(game), -1);
+        assert_eq!(thyme_game_score(game), 0);
+        assert_eq!(thyme_game_discards_allowed(game), 0);
+        assert_eq!(thyme_game_status(game), ThymeStatus::Lost);
+    }
+}
+
+#[test]
+fn describes_results() {
+    let message = |result| unsafe { CStr::from_ptr(thyme_result_message(result as i32)) }.to_str().unwrap();
+    assert_eq!(message(ThymeResult::NeedMultipleRows), MoveError::NeedMultipleRows.to_string());
+    assert_eq!(message(ThymeResult::GameFinished), MoveError::GameFinished.to_string());
+    assert_eq!(message(ThymeResult::Ok), "OK");
+    assert!(message(ThymeResult::InvalidSelection).len() > 0);
+    assert_eq!(message(ThymeResult::NullGame), "The game is null");
+    assert!(thyme_result_message(-1).is_null());
+    assert!(thyme_result_message(ThymeResult::NullGame as i32 + 1).is_null());
+}
+
+#[test]
+fn plays_game_to_end() {
+    let mut expected = Game::<StandardScorer>::with_board(Board::with_seed(3));
+    unsafe {
+        let game = thyme_game_new(3);
+        while let Some((hand, positions)) = expected.available_moves().into_iter().next() {
+            let result = thyme_game_play(game, ThymeHand::from(hand) as i32, selection(&positions), ptr::null_mut());
+            assert_eq!(result, ThymeResult::Ok);
+            expected.play(hand, &positions).unwrap();
+            if expected.is_finished() {
+                break
+            }
+        }
+        assert_eq!(thyme_game_score(game), expected.score());
+        assert!(thyme_game_status(game) != ThymeStatus::InProgress);
+        let result = thyme_game_play(game, ThymeHand::Trash as i32, 1, ptr::null_mut());
+        assert!(result != ThymeResult::Ok);
+        thyme_game_free(game);
+    }
+}
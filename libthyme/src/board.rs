@@ -1,6 +1,16 @@
 extern crate cards;
+extern crate rand;
+
+use self::rand::{Rng,SeedableRng,XorShiftRng};
+use error::ThymeError;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize,Serialize};
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
 
 #[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VPosition {
     Top,
     Middle,
@@ -8,6 +18,7 @@ pub enum VPosition {
 }
 
 #[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum HPosition {
     Left,
     Center,
@@ -15,6 +26,7 @@ pub enum HPosition {
 }
 
 #[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Position {
     /// horizontal position on the board
     pub x: HPosition,
@@ -22,26 +34,130 @@ pub struct Position {
     pub y: VPosition,
 }
 
+/// Positions on the board in the order stacks are dealt
+pub const POSITIONS: [Position; 9] = [
+    Position { x: HPosition::Left, y: VPosition::Top },
+    Position { x: HPosition::Center, y: VPosition::Top },
+    Position { x: HPosition::Right, y: VPosition::Top },
+    Position { x: HPosition::Left, y: VPosition::Middle },
+    Position { x: HPosition::Center, y: VPosition::Middle },
+    Position { x: HPosition::Right, y: VPosition::Middle },
+    Position { x: HPosition::Left, y: VPosition::Bottom },
+    Position { x: HPosition::Center, y: VPosition::Bottom },
+    Position { x: HPosition::Right, y: VPosition::Bottom },
+];
+
+/// Number of cards dealt to the stack at each of `POSITIONS`
+const STACK_SIZES: [usize; 9] = [8, 8, 8, 7, 6, 5, 4, 3, 2];
+
+/// Number of cards in a deck
+pub const DECK_SIZE: usize = 52;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum BoardError {
+    /// A deck must have exactly 52 cards to be dealt
+    WrongDeckSize(usize),
+    /// A card appears more than once in the deck or layout
+    DuplicateCard(cards::card::Card),
+    /// A layout has no stack at a position
+    MissingStack(Position),
+    /// A layout has more than one stack at a position
+    DuplicateStack(Position),
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Stack {
     /// location of the stack on the board
     pub position: Position,
     /// cards in the stack, ordered bottom to top
+    #[cfg_attr(feature = "serde", serde(with = "::serialize::cards"))]
     pub cards: Vec<cards::card::Card>
 }
 
+/// Boards are read through `BoardLayout`, so only layouts accepted by
+/// `Board::with_stacks` can be deserialized
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "BoardLayout"))]
 pub struct Board {
     /// stacks of cards in play, by position
     pub stacks: Vec<Stack>,
     /// the 'lucky' card, not present in any stack
+    #[cfg_attr(feature = "serde", serde(with = "::serialize::card"))]
     pub lucky_card: cards::card::Card,
 }
 
+/// A board as it is written, before the layout is checked
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct BoardLayout {
+    stacks: Vec<Stack>,
+    #[serde(with = "::serialize::card")]
+    lucky_card: cards::card::Card,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<BoardLayout> for Board {
+    type Error = ThymeError;
+
+    fn try_from(layout: BoardLayout) -> Result<Board, ThymeError> {
+        return Board::with_stacks(layout.stacks, layout.lucky_card)
+    }
+}
+
 impl Board {
     /// Create a new board, dealing a 52-card deck into 9 stacks and one
     /// lucky 'extra' card
     pub fn new() -> Board {
-        let (stacks, lucky_card) = deal_deck();
-        Board { stacks: stacks, lucky_card: lucky_card }
+        let deck = cards::deck::Deck::new_shuffled().draw_n(DECK_SIZE).ok()
+            .expect("A new deck has 52 cards");
+        return Board::from_deck(deck).expect("A new deck has no duplicate cards")
+    }
+
+    /// Create a board from a deck shuffled using a seed, so that the same
+    /// seed always deals the same board
+    pub fn with_seed(seed: u64) -> Board {
+        // The last two words keep the generator seed from being all zeros
+        let mut rng = XorShiftRng::from_seed([seed as u32, (seed >> 32) as u32, 0x193a6754, 0xa8a7d469]);
+        let mut deck = cards::deck::Deck::new_unshuffled().draw_n(DECK_SIZE).ok()
+            .expect("A new deck has 52 cards");
+        rng.shuffle(&mut deck);
+        return Board::from_deck(deck).expect("A new deck has no duplicate cards")
+    }
+
+    /// Create a board by dealing a deck in order, filling each stack from
+    /// the top left to the bottom right and keeping the last card as the
+    /// lucky card
+    pub fn from_deck(deck: Vec<cards::card::Card>) -> Result<Board, ThymeError> {
+        if deck.len() != DECK_SIZE {
+            return Err(ThymeError::Board(BoardError::WrongDeckSize(deck.len())))
+        }
+        let mut cards = deck.into_iter();
+        let stacks = POSITIONS.iter().zip(STACK_SIZES.iter())
+            .map(|(position, size)| Stack { position: *position, cards: cards.by_ref().take(*size).collect() })
+            .collect();
+        let lucky_card = cards.next().unwrap();
+        return Board::with_stacks(stacks, lucky_card)
+    }
+
+    /// Create a board from a layout of stacks, which must include a stack
+    /// at every position and may leave out cards
+    pub fn with_stacks(stacks: Vec<Stack>, lucky_card: cards::card::Card) -> Result<Board, ThymeError> {
+        for position in POSITIONS.iter() {
+            match stacks.iter().filter(|s| s.position == *position).count() {
+                0 => return Err(ThymeError::Board(BoardError::MissingStack(*position))),
+                1 => {},
+                _ => return Err(ThymeError::Board(BoardError::DuplicateStack(*position))),
+            }
+        }
+        let mut seen = vec![lucky_card];
+        for card in stacks.iter().flat_map(|s| s.cards.iter()) {
+            if seen.contains(card) {
+                return Err(ThymeError::Board(BoardError::DuplicateCard(*card)))
+            }
+            seen.push(*card);
+        }
+        return Ok(Board { stacks: stacks, lucky_card: lucky_card })
     }
 
     /// Positions on the board
@@ -62,7 +178,7 @@ impl Board {
 
     /// Count the remaining cards in a stack
     pub fn count_cards(&self, position: Position) -> usize {
-        let stack = self.stacks.iter().filter(|s| s.position == position).last();
+        let stack = self.stacks.iter().rfind(|s| s.position == position);
         if stack.is_some() {
             return stack.unwrap().cards.len()
         }
@@ -76,13 +192,17 @@ impl Board {
 
     /// View the cards on top of the stack at a selection of positions, or None
     /// if the request could not be fulfilled for all positions
-    pub fn peek(&mut self, positions: &Vec<Position>) -> Option<Vec<cards::card::Card>> {
-        return self.take_last(positions, false)
+    pub fn peek(&self, positions: &Vec<Position>) -> Option<Vec<cards::card::Card>> {
+        if !self.has_cards(positions) {
+            return None
+        }
+        let stacks = self.stacks.iter().filter(|s| positions.contains(&s.position));
+        return Some(stacks.map(|s| *s.cards.last().unwrap()).collect())
     }
 
     /// View the top card of any stack
-    pub fn top(&mut self, position: Position) -> Option<cards::card::Card> {
-        let result = self.take_last(&vec![position], false);
+    pub fn top(&self, position: Position) -> Option<cards::card::Card> {
+        let result = self.peek(&vec![position]);
         return if result.is_some() { Some(result.unwrap()[0]) } else { None }
     }
 
@@ -90,48 +210,18 @@ impl Board {
     /// positions, or None if the request could not be fulfilled for
     /// all positions
     pub fn pop(&mut self, positions: &Vec<Position>) -> Option<Vec<cards::card::Card>> {
-        return self.take_last(positions, true)
-    }
-
-    fn take_last(&mut self, positions: &Vec<Position>, remove_matches: bool) -> Option<Vec<cards::card::Card>> {
-        let remaining = self.positions_remaining();
-        for position in positions {
-            if !remaining.contains(position) {
-                return None
-            }
+        if !self.has_cards(positions) {
+            return None
         }
         let stacks = self.stacks.iter_mut().filter(|s| positions.contains(&s.position));
-        return Some(stacks.map(|s| if remove_matches {
-            s.cards.pop().unwrap()
-        } else {
-            *s.cards.last().unwrap()
-        }).collect())
+        return Some(stacks.map(|s| s.cards.pop().unwrap()).collect())
     }
-}
 
-/// Divide deck into stacks
-fn deal_deck() -> (Vec<Stack>, cards::card::Card) {
-    let mut deck = cards::deck::Deck::new_shuffled();
-    let stacks = vec![
-        Stack { position: Position { x: HPosition::Left, y: VPosition::Top },
-                cards: deck.draw_n(8).ok().unwrap() },
-        Stack { position: Position { x: HPosition::Center, y: VPosition::Top },
-                cards: deck.draw_n(8).ok().unwrap() },
-        Stack { position: Position { x: HPosition::Right, y: VPosition::Top },
-                cards: deck.draw_n(8).ok().unwrap() },
-        Stack { position: Position { x: HPosition::Left, y: VPosition::Middle },
-                cards: deck.draw_n(7).ok().unwrap() },
-        Stack { position: Position { x: HPosition::Center, y: VPosition::Middle },
-                cards: deck.draw_n(6).ok().unwrap() },
-        Stack { position: Position { x: HPosition::Right, y: VPosition::Middle },
-                cards: deck.draw_n(5).ok().unwrap() },
-        Stack { position: Position { x: HPosition::Left, y: VPosition::Bottom },
-                cards: deck.draw_n(4).ok().unwrap() },
-        Stack { position: Position { x: HPosition::Center, y: VPosition::Bottom },
-                cards: deck.draw_n(3).ok().unwrap() },
-        Stack { position: Position { x: HPosition::Right, y: VPosition::Bottom },
-            cards: deck.draw_n(2).ok().unwrap() }];
-    return (stacks, deck.draw().ok().unwrap())
+    /// True if every position has cards remaining
+    fn has_cards(&self, positions: &Vec<Position>) -> bool {
+        let remaining = self.positions_remaining();
+        return positions.iter().all(|p| remaining.contains(p))
+    }
 }
 
 /// Find all k-combinations of a set of positions
@@ -143,10 +233,7 @@ fn combine(prefix: &mut Vec<Position>, positions: &Vec<Position>) -> Vec<Vec<Pos
     let result = (0..positions.len()).map(|i|{
         let mut items = vec![positions[i]];
         items.extend(prefix.to_vec());
-        let mut pos = vec![];
-        for j in i+1..positions.len() {
-            pos.push(positions[j])
-        }
+        let pos = positions[i+1..].to_vec();
         return combine(&mut items, &pos)
     });
     let mut accumulator = vec![prefix.to_vec()];
@@ -1,4 +1,82 @@
 use cards::card::{Card,Value};
+use game::{MoveError,MoveType};
+
+/// Every hand which can be made from a selection of cards, ordered from the
+/// highest to the lowest ranked hand. An empty result means the selection
+/// is not a hand.
+pub fn classify(cards: &Vec<Card>) -> Vec<MoveType> {
+    let consecutive = is_consecutive(&mut cards.clone());
+    let same_suit = is_same_suit(cards);
+    let mut hands = vec![];
+    match cards.len() {
+        5 => {
+            if consecutive && same_suit {
+                hands.push(MoveType::StraightFlush);
+            }
+            if same_suit {
+                hands.push(MoveType::Flush);
+            }
+            if contains_multiple_of_value(cards, 3) && contains_multiple_of_value(cards, 2) {
+                hands.push(MoveType::FullHouse);
+            }
+            if consecutive {
+                hands.push(MoveType::FiveCardStraight);
+            }
+        },
+        4 if contains_multiple_of_value(cards, 4) => {
+            hands.push(MoveType::FourOfAKind);
+        },
+        3 => {
+            if contains_multiple_of_value(cards, 3) {
+                hands.push(MoveType::ThreeOfAKind);
+            }
+            if consecutive {
+                hands.push(MoveType::ThreeCardStraight);
+            }
+        },
+        2 if contains_multiple_of_value(cards, 2) => {
+            hands.push(MoveType::Pair);
+        },
+        1 => hands.push(MoveType::Trash),
+        _ => {}
+    }
+    return hands
+}
+
+/// Explain why a selection of cards which makes no hand does not qualify,
+/// identifying selections which nearly make a hand
+pub fn diagnose(cards: &Vec<Card>) -> MoveError {
+    if cards.len() == 4 && is_two_pair(cards) {
+        return MoveError::TwoPairIsInvalid
+    } else if cards.len() == 4 && is_same_suit(cards) {
+        return MoveError::FlushNeedsFiveCards
+    } else if (cards.len() == 3 || cards.len() == 5) && has_single_gap(cards) {
+        return MoveError::StraightHasGap
+    }
+    return MoveError::InvalidMove
+}
+
+/// Check that a selection of cards is made of two pairs of distinct values
+pub fn is_two_pair(cards: &Vec<Card>) -> bool {
+    let pairs = cards.iter()
+        .filter(|card| cards.iter().filter(|c| c.value == card.value).count() == 2)
+        .count();
+    return cards.len() == 4 && pairs == 4
+}
+
+/// Check that a selection of cards would have consecutive values if a single
+/// missing value was added
+pub fn has_single_gap(cards: &Vec<Card>) -> bool {
+    if cards.len() < 2 || contains_multiple_of_value(cards, 2)
+        || contains_multiple_of_value(cards, 3) || contains_multiple_of_value(cards, 4) {
+        return false
+    }
+    return [true, false].iter().any(|&ace_low| {
+        let values: Vec<i32> = cards.iter().map(|c| sort_value(c.value, ace_low)).collect();
+        let span = values.iter().max().unwrap() - values.iter().min().unwrap();
+        span == cards.len() as i32
+    })
+}
 
 /// Check that a selection of cards contains cards with the same value a given
 /// number of times
@@ -17,7 +95,7 @@ pub fn is_consecutive(cards: &mut Vec<Card>) -> bool {
         return false
     }
     let ace_low = cards.iter().filter(|a| a.value == Value::Two).count() > 0;
-    cards.sort_by(|a, b| sort_value(a.value, ace_low).cmp(&sort_value(b.value, ace_low)));
+    cards.sort_by_key(|a| sort_value(a.value, ace_low));
     let mut values = cards.iter().map(|&a| sort_value(a.value, ace_low)).peekable();
     for _ in 0..values.len() - 1 {
         if values.next().unwrap() - values.peek().unwrap() != -1 {
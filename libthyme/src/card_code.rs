@@ -0,0 +1,92 @@
+extern crate cards;
+
+use cards::card::{Card,Suit,Value};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum CardStyle {
+    /// Suits written as letters, such as `10H`
+    Ascii,
+    /// Suits written as symbols, such as `10♥`
+    Unicode,
+}
+
+/// Read a card code made of a value (A, 2-10 or T, J, Q, K) and a suit
+/// written in either style, such as `10h`, `QS` or `A♠`
+pub fn parse(code: &str) -> Option<Card> {
+    let upper = code.trim().to_uppercase();
+    let split = upper.char_indices().last().map(|(i, _)| i)?;
+    let (value, suit) = upper.split_at(split);
+    let value = match value {
+        "A" => Value::Ace,
+        "2" => Value::Two,
+        "3" => Value::Three,
+        "4" => Value::Four,
+        "5" => Value::Five,
+        "6" => Value::Six,
+        "7" => Value::Seven,
+        "8" => Value::Eight,
+        "9" => Value::Nine,
+        "10" | "T" => Value::Ten,
+        "J" => Value::Jack,
+        "Q" => Value::Queen,
+        "K" => Value::King,
+        _ => return None,
+    };
+    let suit = match suit {
+        "S" | "\u{2660}" | "\u{2664}" => Suit::Spades,
+        "H" | "\u{2665}" | "\u{2661}" => Suit::Hearts,
+        "D" | "\u{2666}" | "\u{2662}" => Suit::Diamonds,
+        "C" | "\u{2663}" | "\u{2667}" => Suit::Clubs,
+        _ => return None,
+    };
+    return Some(Card { value: value, suit: suit })
+}
+
+/// Read a list of card codes separated by whitespace, such as `5C 6C 7C`
+pub fn parse_all(codes: &str) -> Option<Vec<Card>> {
+    return codes.split_whitespace().map(parse).collect()
+}
+
+/// Write a card as a code which can be read by `parse`
+pub fn format(card: Card, style: CardStyle) -> String {
+    return format!("{}{}", format_value(card.value), format_suit(card.suit, style))
+}
+
+/// Write a list of cards as codes separated by spaces
+pub fn format_all(cards: &Vec<Card>, style: CardStyle) -> String {
+    let codes: Vec<String> = cards.iter().map(|c| format(*c, style)).collect();
+    return codes.join(" ")
+}
+
+/// Printed representation of a card's value
+pub fn format_value(value: Value) -> String {
+    return match value {
+        Value::Ace => "A",
+        Value::Two => "2",
+        Value::Three => "3",
+        Value::Four => "4",
+        Value::Five => "5",
+        Value::Six => "6",
+        Value::Seven => "7",
+        Value::Eight => "8",
+        Value::Nine => "9",
+        Value::Ten => "10",
+        Value::Jack => "J",
+        Value::Queen => "Q",
+        Value::King => "K",
+    }.to_string()
+}
+
+/// Printed representation of a card's suit
+pub fn format_suit(suit: Suit, style: CardStyle) -> String {
+    return match (suit, style) {
+        (Suit::Spades, CardStyle::Ascii) => "S",
+        (Suit::Hearts, CardStyle::Ascii) => "H",
+        (Suit::Diamonds, CardStyle::Ascii) => "D",
+        (Suit::Clubs, CardStyle::Ascii) => "C",
+        (Suit::Spades, CardStyle::Unicode) => "\u{2660}",
+        (Suit::Hearts, CardStyle::Unicode) => "\u{2665}",
+        (Suit::Diamonds, CardStyle::Unicode) => "\u{2666}",
+        (Suit::Clubs, CardStyle::Unicode) => "\u{2663}",
+    }.to_string()
+}
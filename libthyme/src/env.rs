@@ -0,0 +1,130 @@
+extern crate cards;
+
+use board::{Board,Position,POSITIONS,DECK_SIZE};
+use game::{Game,MoveError,MoveType};
+use score::StandardScorer;
+
+/// Hands in the order they are numbered in actions
+pub const MOVE_TYPES: [MoveType; 9] = [
+    MoveType::StraightFlush,
+    MoveType::FourOfAKind,
+    MoveType::Flush,
+    MoveType::FullHouse,
+    MoveType::FiveCardStraight,
+    MoveType::ThreeOfAKind,
+    MoveType::ThreeCardStraight,
+    MoveType::Pair,
+    MoveType::Trash,
+];
+
+/// Number of non-empty sets of positions on the board
+pub const SUBSET_COUNT: usize = (1 << 9) - 1;
+
+/// Number of actions, as every hand played from every set of positions
+pub const ACTION_COUNT: usize = SUBSET_COUNT*9;
+
+/// Number of values in an observation
+pub const OBSERVATION_SIZE: usize = 9*DECK_SIZE + 9 + 1 + 4;
+
+/// Most cards dealt to a single stack
+const MAX_STACK_SIZE: f32 = 8.0;
+
+/// The state of the board as seen by the player, laid out as:
+/// - For each of `POSITIONS`, the card on top of the stack as 52 values, set
+///   to 1 at the index of the card (suit*13 + value, counting from spades
+///   and two) or all 0 if the stack is empty
+/// - For each of `POSITIONS`, the cards left in the stack divided by 8
+/// - The discards allowed divided by the most allowed, or 0 if none are
+/// - The lucky suit as 4 values, set to 1 at the index of the suit
+pub type Observation = Vec<f32>;
+
+/// A game of Sage played one action at a time, for training agents.
+/// Actions are numbered by the set of positions played and the hand played
+/// from them: `subset*9 + hand`, where bit `i` of `subset + 1` selects
+/// `POSITIONS[i]` and `hand` indexes `MOVE_TYPES`.
+pub struct Env {
+    pub game: Game<StandardScorer>,
+}
+
+impl Env {
+
+    /// Create an environment with a game dealt from a seed
+    pub fn new(seed: u64) -> Env {
+        Env { game: Game::with_board(Board::with_seed(seed)) }
+    }
+
+    /// Deal a new game from a seed, returning the first observation
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        self.game = Game::with_board(Board::with_seed(seed));
+        return self.observation()
+    }
+
+    /// Play an action, returning the next observation, the points scored by
+    /// the action and whether the game is finished. Illegal actions are not
+    /// played and return the reason instead.
+    pub fn step(&mut self, action: usize) -> Result<(Observation, i32, bool), MoveError> {
+        let (hand, positions) = decode_action(action).ok_or(MoveError::InvalidMove)?;
+        let score = self.game.score();
+        self.game.play(hand, &positions)?;
+        let reward = self.game.score() - score;
+        return Ok((self.observation(), reward, self.game.is_finished()))
+    }
+
+    /// Encode the current state of the game
+    pub fn observation(&self) -> Observation {
+        let board = &self.game.board;
+        let mut observation = vec![0.0; OBSERVATION_SIZE];
+        for (i, position) in POSITIONS.iter().enumerate() {
+            if let Some(card) = board.top(*position) {
+                observation[i*DECK_SIZE + card_index(card)] = 1.0;
+            }
+            observation[9*DECK_SIZE + i] = board.count_cards(*position) as f32/MAX_STACK_SIZE;
+        }
+        if self.game.discards_allowed_max > 0 {
+            let discards = self.game.discards_allowed as f32/self.game.discards_allowed_max as f32;
+            observation[9*DECK_SIZE + 9] = discards;
+        }
+        observation[9*DECK_SIZE + 10 + board.lucky_card.suit as usize] = 1.0;
+        return observation
+    }
+
+    /// Whether each action can be played, indexed by action
+    pub fn action_mask(&self) -> Vec<bool> {
+        let mut mask = vec![false; ACTION_COUNT];
+        if self.game.is_finished() {
+            return mask
+        }
+        for (hand, positions) in self.game.available_moves() {
+            mask[encode_action(hand, &positions)] = true;
+        }
+        return mask
+    }
+}
+
+/// Number an action playing a hand from a set of positions
+pub fn encode_action(hand: MoveType, positions: &Vec<Position>) -> usize {
+    let subset: usize = POSITIONS.iter().enumerate()
+        .filter(|&(_, p)| positions.contains(p))
+        .map(|(i, _)| 1 << i)
+        .sum();
+    let hand_index = MOVE_TYPES.iter().position(|h| *h == hand).unwrap();
+    return (subset - 1)*MOVE_TYPES.len() + hand_index
+}
+
+/// The hand and positions played by an action, or None if there is no such
+/// action
+pub fn decode_action(action: usize) -> Option<(MoveType, Vec<Position>)> {
+    if action >= ACTION_COUNT {
+        return None
+    }
+    let subset = action/MOVE_TYPES.len() + 1;
+    let positions = POSITIONS.iter().enumerate()
+        .filter(|&(i, _)| subset & (1 << i) != 0)
+        .map(|(_, p)| *p)
+        .collect();
+    return Some((MOVE_TYPES[action % MOVE_TYPES.len()], positions))
+}
+
+fn card_index(card: cards::card::Card) -> usize {
+    return card.suit as usize*13 + card.value as usize
+}
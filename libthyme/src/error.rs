@@ -0,0 +1,95 @@
+use board::BoardError;
+use card_code::{CardStyle,format};
+use game::MoveError;
+use notation::{NotationError,format_position};
+use std::error::Error;
+use std::fmt;
+
+/// Any error raised while building or playing a game. Its message is the
+/// message of the error it holds, so it has no further source.
+#[derive(PartialEq, Clone, Debug)]
+pub enum ThymeError {
+    /// A move could not be played
+    Move(MoveError),
+    /// A board could not be built from a deck or layout
+    Board(BoardError),
+    /// Notation could not be read
+    Notation(NotationError),
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match *self {
+            MoveError::InvalidMove => "This selection is not a hand",
+            MoveError::InvalidHand => "This selection does not match the hand",
+            MoveError::NeedMultipleRows => "A hand must use cards from more than one row",
+            MoveError::NoMovesRemain => "Game Over - No moves left",
+            MoveError::NoDiscardsRemain => "No discards remain - play a hand to earn one",
+            MoveError::TwoPairIsInvalid => "Two pair is not a hand - play each pair separately",
+            MoveError::StraightHasGap => "A straight cannot skip a value",
+            MoveError::FlushNeedsFiveCards => "A flush needs five cards of the same suit",
+            MoveError::OutOfTime => "Game Over - Out of time",
+            MoveError::GameFinished => "The game has already been won or lost",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BoardError::WrongDeckSize(size) => write!(f, "A deck must have 52 cards, not {}", size),
+            BoardError::DuplicateCard(card) => write!(f, "{} is dealt more than once", format(card, CardStyle::Ascii)),
+            BoardError::MissingStack(position) => write!(f, "No stack is at {}", format_position(position)),
+            BoardError::DuplicateStack(position) => write!(f, "More than one stack is at {}", format_position(position)),
+        }
+    }
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NotationError::InvalidCard(ref code) => write!(f, "'{}' is not a card", code),
+            NotationError::InvalidLabel(ref label) => write!(f, "'{}' is not a stack position, 'lucky' or 'discards'", label),
+            NotationError::DuplicateLabel(ref label) => write!(f, "'{}' is given more than once", label),
+            NotationError::MissingLuckyCard => write!(f, "The lucky card was not given"),
+            NotationError::InvalidDiscards(ref discards) => write!(f, "'{}' is not written as allowed/max discards", discards),
+        }
+    }
+}
+
+impl fmt::Display for ThymeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ThymeError::Move(error) => write!(f, "{}", error),
+            ThymeError::Board(error) => write!(f, "{}", error),
+            ThymeError::Notation(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for MoveError {}
+
+impl Error for BoardError {}
+
+impl Error for NotationError {}
+
+impl Error for ThymeError {}
+
+impl From<MoveError> for ThymeError {
+    fn from(error: MoveError) -> ThymeError {
+        return ThymeError::Move(error)
+    }
+}
+
+impl From<BoardError> for ThymeError {
+    fn from(error: BoardError) -> ThymeError {
+        return ThymeError::Board(error)
+    }
+}
+
+impl From<NotationError> for ThymeError {
+    fn from(error: NotationError) -> ThymeError {
+        return ThymeError::Notation(error)
+    }
+}
@@ -2,8 +2,14 @@ extern crate cards;
 
 use board::{Board,Position};
 use card::*;
-use score::{Scorer,Play};
+use score::{Scorer,Play,Score};
+use std::time::{Duration,Instant};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize,Serialize};
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Game<T: Scorer> {
     pub board: Board,
     /// Number of times discarding a single card is allowed
@@ -12,9 +18,25 @@ pub struct Game<T: Scorer> {
     pub discards_allowed_max: i32,
     /// Score calculator
     pub scorer: T,
+    /// Every play made, in order
+    pub plays: Vec<Play>,
+    /// When the game began
+    #[cfg_attr(feature = "serde", serde(with = "::serialize::instant"))]
+    pub started: Instant,
+    /// Time taken to finish the game, once the board is cleared or no moves
+    /// remain
+    pub stopped: Option<Duration>,
+    /// Time allowed to clear the board, if the game is timed
+    pub time_limit: Option<Duration>,
+    /// Whether the game has been won or lost
+    pub status: GameStatus,
+    /// When the clock was paused, if it is paused
+    #[cfg_attr(feature = "serde", serde(with = "::serialize::optional_instant"))]
+    pub paused: Option<Instant>,
 }
 
 #[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MoveError {
     /// The move cannot be played given the current board
     InvalidMove,
@@ -26,11 +48,48 @@ pub enum MoveError {
     NoDiscardsRemain,
     /// Two pair is not a sage
     TwoPairIsInvalid,
+    /// The cards would be a straight if not for a missing value
+    StraightHasGap,
+    /// A flush must be made of five cards
+    FlushNeedsFiveCards,
     /// The move cannot be played given the current hand
     InvalidHand,
+    /// The time limit has passed
+    OutOfTime,
+    /// The game has already been won or lost
+    GameFinished,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GameStatus {
+    /// Moves can still be played
+    InProgress,
+    /// Every card was cleared from the board
+    Won,
+    /// No moves remain or the time ran out with cards left on the board
+    Lost { cards_left: usize },
 }
 
 #[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CardStatus {
+    /// The card was played as part of a hand
+    Played,
+    /// The card was discarded
+    Trashed,
+    /// The card is on top of a stack
+    Visible,
+    /// The card is in a stack beneath other cards
+    Buried,
+    /// The card is the lucky card, which is never in play
+    Lucky,
+    /// The card was not dealt in this game
+    Absent,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MoveType {
     /// Five consecutive cards of the same suit
     StraightFlush,
@@ -55,7 +114,11 @@ pub enum MoveType {
 impl<T: Sized> Game<T> where T: Scorer {
 
     pub fn new() -> Game<T> {
-        let board = Board::new();
+        return Game::with_board(Board::new())
+    }
+
+    /// Create a game played on an existing board, with all discards allowed
+    pub fn with_board(board: Board) -> Game<T> {
         let lucky_card = cards::card::Card {
             value: board.lucky_card.value,
             suit: board.lucky_card.suit
@@ -65,67 +128,214 @@ impl<T: Sized> Game<T> where T: Scorer {
             discards_allowed: 2,
             discards_allowed_max: 2,
             scorer: Scorer::new(lucky_card),
+            plays: vec![],
+            started: Instant::now(),
+            stopped: None,
+            time_limit: None,
+            status: GameStatus::InProgress,
+            paused: None,
+        }
+    }
+
+    /// Create a game on a new board which must be cleared within a time limit
+    pub fn timed(time_limit: Duration) -> Game<T> {
+        let mut game = Game::new();
+        game.time_limit = Some(time_limit);
+        return game
+    }
+
+    /// Time spent playing, which stops while paused and when the game is
+    /// finished or the time limit passes
+    pub fn elapsed(&self) -> Duration {
+        let now = self.paused.unwrap_or_else(Instant::now);
+        let elapsed = self.stopped.unwrap_or(now.duration_since(self.started));
+        return match self.time_limit {
+            Some(limit) => elapsed.min(limit),
+            None => elapsed,
         }
     }
 
+    /// Stop the clock until the game is resumed
+    pub fn pause(&mut self) {
+        if self.paused.is_none() {
+            self.paused = Some(Instant::now());
+        }
+    }
+
+    /// Restart the clock after a pause, not counting the time paused
+    pub fn resume(&mut self) {
+        if let Some(paused) = self.paused.take() {
+            self.started += paused.elapsed();
+        }
+    }
+
+    /// Time left to clear the board, if the game is timed
+    pub fn time_remaining(&self) -> Option<Duration> {
+        return self.time_limit.map(|limit| limit - self.elapsed())
+    }
+
+    /// True if the game is timed and the time limit has passed
+    pub fn is_out_of_time(&self) -> bool {
+        return self.time_remaining() == Some(Duration::from_secs(0))
+    }
+
     /// Current score
-    pub fn score(&mut self) -> i32 {
+    pub fn score(&self) -> i32 {
         let completion = self.moves_remaining();
         self.scorer.score(completion)
     }
 
+    /// True once the game has been won or lost
+    pub fn is_finished(&self) -> bool {
+        return self.status != GameStatus::InProgress
+    }
+
+    /// Check whether the game has been won or lost since the status was last
+    /// updated, such as by running out of time, returning the new status if
+    /// it changed. The status is updated after every play.
+    pub fn update_status(&mut self) -> Option<GameStatus> {
+        if self.is_finished() {
+            return None
+        }
+        let cards_left = self.board.count_all_cards();
+        if cards_left == 0 {
+            self.status = GameStatus::Won;
+        } else if self.is_out_of_time() || !self.moves_remaining() {
+            self.status = GameStatus::Lost { cards_left: cards_left };
+        } else {
+            return None
+        }
+        self.stop_clock();
+        return Some(self.status)
+    }
+
+    /// Stop the clock, awarding the seconds left as a bonus if the board was
+    /// cleared in a timed game
+    fn stop_clock(&mut self) {
+        let remaining = self.time_remaining();
+        self.stopped = Some(self.elapsed());
+        if let (Some(remaining), 0) = (remaining, self.board.count_all_cards()) {
+            self.scorer.add_time_bonus(remaining.as_secs() as i32);
+        }
+    }
+
     /// True if any more moves can be played
-    pub fn moves_remaining(&mut self) -> bool {
-        return self.board.hands_remaining().iter().find(|h| self.check(&h).is_ok()).is_some()
-        || self.discards_allowed > 0
+    pub fn moves_remaining(&self) -> bool {
+        return self.board.hands_remaining().iter().find(|h| self.check(h).is_ok()).is_some()
+        || (self.discards_allowed > 0 && self.board.count_all_cards() > 0)
+    }
+
+    /// Every move which can be played on the current board, as each hand the
+    /// cards at a set of positions can make
+    pub fn available_moves(&self) -> Vec<(MoveType, Vec<Position>)> {
+        let mut moves = vec![];
+        for positions in self.board.hands_remaining() {
+            if let Ok(hands) = self.check_all(&positions) {
+                moves.extend(hands.into_iter().map(|hand| (hand, positions.clone())));
+            }
+        }
+        return moves
     }
 
-    /// Play the cards at the top of a set of stacks, updating score and
-    /// discards_allowed if applicable
+    /// Play the cards at the top of a set of stacks as a hand, updating score
+    /// and discards_allowed if applicable. Any hand the cards can make may be
+    /// played, though `check` reports the highest ranked one. No moves can
+    /// be played once the game is finished.
     pub fn play(&mut self, hand: MoveType, positions: &Vec<Position>) -> Result<Play, MoveError> {
-        let check = self.check(positions);
-        if check.is_ok() {
-            if check.ok().unwrap() == hand {
-                let cards = self.board.pop(&positions);
-                if hand == MoveType::Trash {
-                    self.discards_allowed -= 1;
-                } else if self.discards_allowed < self.discards_allowed_max {
-                    self.discards_allowed += 1;
-                }
-                let remaining = self.board.positions_remaining();
-                let cleared = positions.iter().filter(|&p| !remaining.contains(p)).map(|p| *p).collect();
-                return Ok(Play {
-                    cards: cards.unwrap(), hand: hand, cleared_positions: cleared
-                })
-            } else {
-                return Err(MoveError::InvalidHand);
+        if self.is_out_of_time() {
+            self.update_status();
+            return Err(MoveError::OutOfTime);
+        }
+        if self.is_finished() {
+            return Err(MoveError::GameFinished);
+        }
+        let hands = self.check_all(positions)?;
+        if !hands.contains(&hand) {
+            return Err(MoveError::InvalidHand);
+        }
+        let cards = self.board.pop(positions).ok_or(MoveError::InvalidMove)?;
+        if hand == MoveType::Trash {
+            self.discards_allowed -= 1;
+        } else if self.discards_allowed < self.discards_allowed_max {
+            self.discards_allowed += 1;
+        }
+        let remaining = self.board.positions_remaining();
+        let cleared = uniq(positions.clone()).into_iter().filter(|p| !remaining.contains(p)).collect();
+        let play = Play {
+            cards: cards, hand: hand, cleared_positions: cleared
+        };
+        self.scorer.add_play(play.clone());
+        self.plays.push(play.clone());
+        self.update_status();
+        return Ok(play)
+    }
+
+    /// Determine where a card is in this game, from the board and the plays
+    /// made so far
+    pub fn card_status(&self, card: cards::card::Card) -> CardStatus {
+        if card == self.board.lucky_card {
+            return CardStatus::Lucky
+        }
+        for stack in &self.board.stacks {
+            if stack.cards.last() == Some(&card) {
+                return CardStatus::Visible
+            } else if stack.cards.contains(&card) {
+                return CardStatus::Buried
             }
         }
-        return Err(check.err().unwrap());
+        let play = self.plays.iter().find(|p| p.cards.contains(&card));
+        return match play {
+            Some(p) if p.hand == MoveType::Trash => CardStatus::Trashed,
+            Some(_) => CardStatus::Played,
+            None => CardStatus::Absent,
+        }
     }
 
-    /// Determine what move would result from playing the cards on top of a
-    /// set of stacks
-    pub fn check(&mut self, positions: &Vec<Position>) -> Result<MoveType, MoveError> {
+    /// Compute the score which would be awarded for playing the cards on top
+    /// of a set of stacks as a hand, including any stacks it would clear
+    pub fn check_score(&self, hand: MoveType, positions: &Vec<Position>) -> Result<Score, MoveError> {
+        let hands = self.check_all(positions)?;
+        if !hands.contains(&hand) {
+            return Err(MoveError::InvalidHand);
+        }
+        let cleared = uniq(positions.clone()).into_iter().filter(|p| self.board.count_cards(*p) == 1).collect();
+        return Ok(self.scorer.check_play(Play {
+            cards: self.board.peek(positions).unwrap(),
+            cleared_positions: cleared,
+            hand: hand
+        }))
+    }
+
+    /// Determine the highest ranked move which would result from playing the
+    /// cards on top of a set of stacks
+    pub fn check(&self, positions: &Vec<Position>) -> Result<MoveType, MoveError> {
+        return self.check_all(positions).map(|hands| hands[0])
+    }
+
+    /// Determine every move which could result from playing the cards on top
+    /// of a set of stacks, ordered from the highest to the lowest ranked. Each
+    /// stack may only be played once.
+    pub fn check_all(&self, positions: &Vec<Position>) -> Result<Vec<MoveType>, MoveError> {
         let rows = uniq(positions.iter().map(|p| p.y).collect());
         let result = self.board.peek(positions);
-        if rows.len() < 2 && positions.len() > 1 {
+        if uniq(positions.clone()).len() < positions.len() {
+            return Err(MoveError::InvalidMove);
+        } else if rows.len() < 2 && positions.len() > 1 {
             return Err(MoveError::NeedMultipleRows);
         } else if result.is_some() {
-            return self.check_cards(&mut result.unwrap())
+            return self.check_cards(&result.unwrap())
         }
         return Err(MoveError::InvalidMove)
     }
 
-    fn check_cards(&self, cards: &mut Vec<cards::card::Card>) -> Result<MoveType, MoveError> {
-        match cards.len() {
-            5 => return check_five(cards),
-            4 => return check_four(cards),
-            3 => return check_three(cards),
-            2 => return check_two(cards),
-            1 => return check_one(self.discards_allowed),
-            _ => return Err(MoveError::InvalidMove),
+    fn check_cards(&self, cards: &Vec<cards::card::Card>) -> Result<Vec<MoveType>, MoveError> {
+        let hands = classify(cards);
+        if hands.is_empty() {
+            return Err(diagnose(cards));
+        } else if hands[0] == MoveType::Trash {
+            return check_one(self.discards_allowed).map(|hand| vec![hand]);
         }
+        return Ok(hands)
     }
 }
 
@@ -139,42 +349,6 @@ fn uniq<T>(items: Vec<T>) -> Vec<T> where T: PartialEq {
     return result;
 }
 
-fn check_five(cards: &mut Vec<cards::card::Card>) -> Result<MoveType, MoveError> {
-    if is_consecutive(cards) && is_same_suit(cards) {
-        return Ok(MoveType::StraightFlush)
-    } else if is_consecutive(cards) {
-        return Ok(MoveType::FiveCardStraight)
-    } else if is_same_suit(cards) {
-        return Ok(MoveType::Flush)
-    } else if contains_multiple_of_value(cards, 3) && contains_multiple_of_value(cards, 2) {
-        return Ok(MoveType::FullHouse)
-    }
-    return Err(MoveError::InvalidMove);
-}
-
-fn check_four(cards: &mut Vec<cards::card::Card>) -> Result<MoveType, MoveError> {
-    if contains_multiple_of_value(cards, 4) {
-        return Ok(MoveType::FourOfAKind)
-    }
-    return Err(MoveError::InvalidMove);
-}
-
-fn check_three(cards: &mut Vec<cards::card::Card>) -> Result<MoveType, MoveError> {
-    if is_consecutive(cards) {
-        return Ok(MoveType::ThreeCardStraight)
-    } else if contains_multiple_of_value(cards, 3) {
-        return Ok(MoveType::ThreeOfAKind)
-    }
-    return Err(MoveError::InvalidMove);
-}
-
-fn check_two(cards: &mut Vec<cards::card::Card>) -> Result<MoveType, MoveError> {
-    if contains_multiple_of_value(cards, 2) {
-        return Ok(MoveType::Pair)
-    }
-    return Err(MoveError::InvalidMove);
-}
-
 fn check_one(trashes_remaining: i32) -> Result<MoveType, MoveError> {
     if trashes_remaining > 0 {
         return Ok(MoveType::Trash)
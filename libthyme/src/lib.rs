@@ -1,6 +1,16 @@
 extern crate cards;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 pub mod game;
 pub mod board;
 pub mod card;
+pub mod card_code;
+pub mod env;
+pub mod error;
+pub mod notation;
+pub mod race;
 pub mod score;
+#[cfg(feature = "serde")]
+pub mod serialize;
+pub mod strategy;
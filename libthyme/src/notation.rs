@@ -0,0 +1,157 @@
+extern crate cards;
+
+use cards::card::Card;
+use card_code::{CardStyle,format,format_all,parse};
+use board::{Board,Stack,Position,HPosition,VPosition,POSITIONS};
+use error::ThymeError;
+use game::Game;
+use score::Scorer;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum NotationError {
+    /// A card code could not be read
+    InvalidCard(String),
+    /// A line is not labelled with a stack position, lucky card or discards
+    InvalidLabel(String),
+    /// More than one line has the same label
+    DuplicateLabel(String),
+    /// The lucky card was not given
+    MissingLuckyCard,
+    /// The discards could not be read as `allowed/max`, or more are allowed
+    /// than the maximum
+    InvalidDiscards(String),
+}
+
+/// Label of a position, such as `TL` for the top left stack
+pub fn format_position(position: Position) -> String {
+    let row = match position.y {
+        VPosition::Top => "T",
+        VPosition::Middle => "M",
+        VPosition::Bottom => "B",
+    };
+    let column = match position.x {
+        HPosition::Left => "L",
+        HPosition::Center => "C",
+        HPosition::Right => "R",
+    };
+    return format!("{}{}", row, column)
+}
+
+/// Read a position label written by `format_position`
+pub fn parse_position(label: &str) -> Option<Position> {
+    return POSITIONS.iter().find(|p| format_position(**p) == label.to_uppercase()).cloned()
+}
+
+/// A board is written as one line per stack, listing the cards from bottom
+/// to top, followed by the lucky card:
+///
+/// ```text
+/// TL: 2S 5H 9C AD 10H KD QS 3C
+/// ...
+/// BR: 4D JC
+/// lucky: KH
+/// ```
+///
+/// Lines may instead be separated by `;`, and labels may be written in any
+/// case. Stacks which are not listed are read as empty, though no label or
+/// card may be written twice. Notation which cannot be read is reported as a
+/// `ThymeError::Notation`, and stacks which do not make a board as a
+/// `ThymeError::Board`.
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for stack in &self.stacks {
+            writeln!(f, "{}: {}", format_position(stack.position), format_all(&stack.cards, CardStyle::Ascii))?;
+        }
+        write!(f, "lucky: {}", format(self.lucky_card, CardStyle::Ascii))
+    }
+}
+
+impl FromStr for Board {
+    type Err = ThymeError;
+
+    fn from_str(notation: &str) -> Result<Board, ThymeError> {
+        let mut stacks: Vec<Stack> = POSITIONS.iter()
+            .map(|p| Stack { position: *p, cards: vec![] })
+            .collect();
+        let mut lucky_card = None;
+        for (label, content) in fields(notation)? {
+            if label.eq_ignore_ascii_case("lucky") {
+                lucky_card = Some(parse_card(content)?);
+                continue;
+            }
+            let position = parse_position(label).ok_or(NotationError::InvalidLabel(label.to_string()))?;
+            let stack = stacks.iter_mut().find(|s| s.position == position).unwrap();
+            stack.cards = content.split_whitespace().map(parse_card).collect::<Result<Vec<Card>, NotationError>>()?;
+        }
+        return match lucky_card {
+            Some(card) => Board::with_stacks(stacks, card),
+            None => Err(ThymeError::Notation(NotationError::MissingLuckyCard)),
+        }
+    }
+}
+
+/// A game is written as its board followed by the discards allowed out of
+/// the maximum, such as `discards: 1/2`. When not given, the discards are
+/// `2/2`.
+impl<T: Scorer> fmt::Display for Game<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.board)?;
+        write!(f, "discards: {}/{}", self.discards_allowed, self.discards_allowed_max)
+    }
+}
+
+impl<T: Scorer> FromStr for Game<T> {
+    type Err = ThymeError;
+
+    fn from_str(notation: &str) -> Result<Game<T>, ThymeError> {
+        let mut board_lines = vec![];
+        let mut discards = None;
+        for (label, content) in fields(notation)? {
+            if label.eq_ignore_ascii_case("discards") {
+                discards = Some(parse_discards(content)?);
+            } else {
+                board_lines.push(format!("{}: {}", label, content));
+            }
+        }
+        let mut game = Game::with_board(board_lines.join("\n").parse()?);
+        if let Some((allowed, max)) = discards {
+            game.discards_allowed = allowed;
+            game.discards_allowed_max = max;
+        }
+        return Ok(game)
+    }
+}
+
+fn parse_card(code: &str) -> Result<Card, NotationError> {
+    return parse(code).ok_or(NotationError::InvalidCard(code.to_string()))
+}
+
+/// Split notation into labelled lines, skipping blank lines. No label may be
+/// used more than once, whatever its case.
+fn fields(notation: &str) -> Result<Vec<(&str, &str)>, NotationError> {
+    let fields = notation.split(['\n', ';'])
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.find(':') {
+            Some(index) => Ok((line[..index].trim(), line[index + 1..].trim())),
+            None => Err(NotationError::InvalidLabel(line.to_string())),
+        })
+        .collect::<Result<Vec<(&str, &str)>, NotationError>>()?;
+    for (i, (label, _)) in fields.iter().enumerate() {
+        if fields[..i].iter().any(|(other, _)| other.eq_ignore_ascii_case(label)) {
+            return Err(NotationError::DuplicateLabel(label.to_string()))
+        }
+    }
+    return Ok(fields)
+}
+
+fn parse_discards(content: &str) -> Result<(i32, i32), NotationError> {
+    let error = NotationError::InvalidDiscards(content.to_string());
+    let mut parts = content.split('/').map(|part| part.trim().parse::<i32>());
+    return match (parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(allowed)), Some(Ok(max)), None) if 0 <= allowed && allowed <= max => Ok((allowed, max)),
+        _ => Err(error),
+    }
+}
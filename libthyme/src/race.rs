@@ -0,0 +1,116 @@
+use board::{Board,DECK_SIZE};
+use game::{Game,GameStatus};
+use score::Scorer;
+
+/// Progress of one player's game in a race
+#[derive(PartialEq, Clone, Debug)]
+pub struct Racer {
+    pub name: String,
+    pub score: i32,
+    pub cards_remaining: usize,
+    pub status: GameStatus,
+    /// Whether the player left before their game was finished
+    pub forfeited: bool,
+}
+
+/// Players racing to clear boards dealt from the same seed. The first player
+/// to clear their board wins, or if no one does, the player with the highest
+/// score once every game is finished.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Race {
+    /// Seed every board in the race is dealt from
+    pub seed: u64,
+    pub racers: Vec<Racer>,
+    /// Indexes of racers in the order their games finished
+    finish_order: Vec<usize>,
+}
+
+impl Racer {
+
+    /// Whether the player can no longer change their score
+    pub fn is_finished(&self) -> bool {
+        return self.forfeited || self.status != GameStatus::InProgress
+    }
+}
+
+impl Race {
+
+    /// Create a race between a number of players, named by the order they
+    /// joined in
+    pub fn new(seed: u64, players: usize) -> Race {
+        // Every card but the lucky card is dealt to the stacks
+        let cards_remaining = DECK_SIZE - 1;
+        let racers = (0..players).map(|i| Racer {
+            name: format!("Player {}", i + 1),
+            score: 0,
+            cards_remaining: cards_remaining,
+            status: GameStatus::InProgress,
+            forfeited: false,
+        }).collect();
+        Race { seed: seed, racers: racers, finish_order: vec![] }
+    }
+
+    /// Deal the board every player races on
+    pub fn board(&self) -> Board {
+        return Board::with_seed(self.seed)
+    }
+
+    /// Record the progress of a player's game
+    pub fn update<T: Scorer>(&mut self, index: usize, game: &Game<T>) {
+        let racer = &mut self.racers[index];
+        if racer.is_finished() {
+            return
+        }
+        racer.score = game.score();
+        racer.cards_remaining = game.board.count_all_cards();
+        racer.status = game.status;
+        if racer.is_finished() {
+            self.finish_order.push(index);
+        }
+    }
+
+    /// Record a player leaving, forfeiting the race if their game is not
+    /// finished
+    pub fn leave(&mut self, index: usize) {
+        let racer = &mut self.racers[index];
+        if !racer.is_finished() {
+            racer.forfeited = true;
+            self.finish_order.push(index);
+        }
+    }
+
+    /// Whether the winner has been decided
+    pub fn is_finished(&self) -> bool {
+        return self.has_cleared_board() || self.racers.iter().all(|r| r.is_finished())
+    }
+
+    /// Index of the winning player, or None if the race is not finished or
+    /// every player forfeited
+    pub fn winner(&self) -> Option<usize> {
+        let cleared = self.finish_order.iter()
+            .find(|i| self.racers[**i].status == GameStatus::Won);
+        if let Some(index) = cleared {
+            return Some(*index)
+        }
+        if !self.is_finished() {
+            return None
+        }
+        // The earliest to finish wins a tie
+        let mut winner: Option<usize> = None;
+        for index in self.finish_order.iter() {
+            let racer = &self.racers[*index];
+            let best = match winner {
+                Some(w) => racer.score > self.racers[w].score,
+                None => true,
+            };
+            if !racer.forfeited && best {
+                winner = Some(*index);
+            }
+        }
+        return winner
+    }
+
+    fn has_cleared_board(&self) -> bool {
+        return self.racers.iter().any(|r| r.status == GameStatus::Won)
+    }
+}
@@ -3,6 +3,14 @@ extern crate cards;
 use board::{Position,VPosition};
 use game::MoveType;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize,Serialize};
+
+/// Points awarded for each second left after clearing the board in a timed
+/// game
+const TIME_BONUS_PER_SECOND: i32 = 2;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Score {
     /// The increase in score attained by playing this hand
     pub value: i32,
@@ -12,9 +20,19 @@ pub struct Score {
     pub multiplier: i32,
 }
 
+impl Score {
+
+    /// Total points awarded, after applying the multiplier and bonus
+    pub fn total(&self) -> i32 {
+        return self.value * self.multiplier + self.bonus
+    }
+}
+
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Play {
     /// Cards played
+    #[cfg_attr(feature = "serde", serde(with = "::serialize::cards"))]
     pub cards: Vec<cards::card::Card>,
     /// All positions/stacks cleared by this play
     pub cleared_positions: Vec<Position>,
@@ -37,6 +55,10 @@ pub trait Scorer {
     /// Bonus awarded for clearing a position
     fn bonus(&self, position: Position) -> i32;
 
+    /// Update the score with a bonus for the seconds left after clearing the
+    /// board in a timed game. No bonus is awarded unless implemented.
+    fn add_time_bonus(&mut self, _seconds: i32) {}
+
     /// The score including any completion bonuses or multipliers, etc
     fn score(&self, completion: bool) -> i32;
 
@@ -46,7 +68,10 @@ pub trait Scorer {
 
 /// Standard (no fifteens) scoring hands, double bonus awarded for cards
 /// with the lucky card's suit
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StandardScorer {
+    #[cfg_attr(feature = "serde", serde(with = "::serialize::suit"))]
     lucky_suit: cards::card::Suit,
     total: i32,
 }
@@ -79,7 +104,11 @@ impl Scorer for StandardScorer {
 
     fn add_play(&mut self, play: Play) {
         let score = self.check_play(play);
-        self.total += (score.value * score.multiplier) + score.bonus;
+        self.total += score.total();
+    }
+
+    fn add_time_bonus(&mut self, seconds: i32) {
+        self.total += seconds * TIME_BONUS_PER_SECOND;
     }
 
     fn score(&self, _: bool) -> i32 {
@@ -0,0 +1,94 @@
+//! Serialization of types from the cards crate and the clock, for use with
+//! `#[serde(with = "...")]`. Cards are written as the codes read by
+//! `card_code::parse`, such as `10H`.
+
+/// A single card, written as a card code
+pub mod card {
+    use cards::card::Card;
+    use card_code::{CardStyle,format,parse};
+    use serde::{Deserialize,Deserializer,Serializer};
+    use serde::de::Error;
+
+    pub fn serialize<S: Serializer>(card: &Card, serializer: S) -> Result<S::Ok, S::Error> {
+        return serializer.serialize_str(&format(*card, CardStyle::Ascii))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Card, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        return parse(&code).ok_or(D::Error::custom(format!("invalid card '{}'", code)))
+    }
+}
+
+/// A list of cards, written as a list of card codes
+pub mod cards {
+    use cards::card::Card;
+    use card_code::{CardStyle,format,parse};
+    use serde::{Deserialize,Deserializer,Serializer};
+    use serde::de::Error;
+    use serde::ser::SerializeSeq;
+
+    pub fn serialize<S: Serializer>(cards: &Vec<Card>, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(cards.len()))?;
+        for card in cards {
+            seq.serialize_element(&format(*card, CardStyle::Ascii))?;
+        }
+        return seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Card>, D::Error> {
+        let codes = Vec::<String>::deserialize(deserializer)?;
+        return codes.iter()
+            .map(|code| parse(code).ok_or(D::Error::custom(format!("invalid card '{}'", code))))
+            .collect()
+    }
+}
+
+/// A suit, written as a letter such as `H`
+pub mod suit {
+    use cards::card::Suit;
+    use card_code::{CardStyle,format_suit,parse};
+    use serde::{Deserialize,Deserializer,Serializer};
+    use serde::de::Error;
+
+    pub fn serialize<S: Serializer>(suit: &Suit, serializer: S) -> Result<S::Ok, S::Error> {
+        return serializer.serialize_str(&format_suit(*suit, CardStyle::Ascii))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Suit, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        return parse(&format!("A{}", code))
+            .map(|card| card.suit)
+            .ok_or(D::Error::custom(format!("invalid suit '{}'", code)))
+    }
+}
+
+/// A point in time, written as the time passed since then so it can be
+/// restored relative to when it is read
+pub mod instant {
+    use serde::{Deserialize,Deserializer,Serialize,Serializer};
+    use std::time::{Duration,Instant};
+
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        return instant.elapsed().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        let elapsed = Duration::deserialize(deserializer)?;
+        return Ok(Instant::now().checked_sub(elapsed).unwrap_or_else(Instant::now))
+    }
+}
+
+/// An optional point in time, written in the same way as `instant`
+pub mod optional_instant {
+    use serde::{Deserialize,Deserializer,Serialize,Serializer};
+    use std::time::{Duration,Instant};
+
+    pub fn serialize<S: Serializer>(instant: &Option<Instant>, serializer: S) -> Result<S::Ok, S::Error> {
+        return instant.map(|i| i.elapsed()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Instant>, D::Error> {
+        let elapsed = Option::<Duration>::deserialize(deserializer)?;
+        return Ok(elapsed.map(|e| Instant::now().checked_sub(e).unwrap_or_else(Instant::now)))
+    }
+}
@@ -0,0 +1,145 @@
+extern crate rand;
+
+use board::Position;
+use game::{Game,MoveType};
+use score::Scorer;
+use self::rand::Rng;
+
+/// Names of the strategies created by `strategy_named`
+pub const STRATEGIES: [&str; 5] = ["greedy", "random", "solver", "clear-stacks", "conserve-discards"];
+
+/// Penalty applied by lookahead when a line of play leaves cards on the board
+/// with no moves remaining
+const DEAD_END_PENALTY: i32 = 1000;
+
+pub trait Strategy<T: Scorer> {
+
+    /// Choose the next move to play on a game, as a hand and the positions of
+    /// the cards to use, or None if no move can be played
+    fn choose(&self, game: &Game<T>) -> Option<(MoveType, Vec<Position>)>;
+}
+
+/// Play the move awarding the most points right now
+pub struct GreedyStrategy;
+
+/// Play the move clearing the most stacks, or failing that the move leaving
+/// the fewest cards in the stacks it uses
+pub struct ClearStacksStrategy;
+
+/// Play the move leaving the most hands available afterwards, so discards
+/// are saved for when no hand can be played
+pub struct ConserveDiscardsStrategy;
+
+/// Play any hand, discarding only when no hand can be played
+pub struct RandomStrategy;
+
+/// Play the move awarding the most points over a number of moves
+pub struct LookaheadStrategy {
+    /// Number of moves to search, including the move chosen
+    pub depth: usize,
+}
+
+impl<T: Scorer> Strategy<T> for GreedyStrategy {
+
+    fn choose(&self, game: &Game<T>) -> Option<(MoveType, Vec<Position>)> {
+        return candidate_moves(game).into_iter()
+            .max_by_key(|(hand, positions)| points(game, *hand, positions))
+    }
+}
+
+impl<T: Scorer> Strategy<T> for RandomStrategy {
+
+    fn choose(&self, game: &Game<T>) -> Option<(MoveType, Vec<Position>)> {
+        let mut moves = candidate_moves(game);
+        if moves.is_empty() {
+            return None
+        }
+        let index = rand::thread_rng().gen_range(0, moves.len());
+        return Some(moves.swap_remove(index))
+    }
+}
+
+impl<T: Scorer> Strategy<T> for ClearStacksStrategy {
+
+    fn choose(&self, game: &Game<T>) -> Option<(MoveType, Vec<Position>)> {
+        return candidate_moves(game).into_iter().max_by_key(|(hand, positions)| {
+            let counts: Vec<usize> = positions.iter().map(|p| game.board.count_cards(*p)).collect();
+            let cleared = counts.iter().filter(|&&c| c == 1).count();
+            let remaining = counts.iter().sum::<usize>() as i32;
+            (cleared, -remaining, points(game, *hand, positions))
+        })
+    }
+}
+
+impl<T: Scorer + Clone> Strategy<T> for ConserveDiscardsStrategy {
+
+    fn choose(&self, game: &Game<T>) -> Option<(MoveType, Vec<Position>)> {
+        return candidate_moves(game).into_iter().max_by_key(|(hand, positions)| {
+            let mut next = game.clone();
+            next.play(*hand, positions).expect("Candidate moves are available moves");
+            let hands = candidate_moves(&next).iter().filter(|(h, _)| *h != MoveType::Trash).count();
+            (hands, points(game, *hand, positions))
+        })
+    }
+}
+
+impl LookaheadStrategy {
+
+    pub fn new(depth: usize) -> LookaheadStrategy {
+        LookaheadStrategy { depth: depth }
+    }
+}
+
+impl<T: Scorer + Clone> Strategy<T> for LookaheadStrategy {
+
+    fn choose(&self, game: &Game<T>) -> Option<(MoveType, Vec<Position>)> {
+        return candidate_moves(game).into_iter().max_by_key(|(hand, positions)| {
+            let mut next = game.clone();
+            next.play(*hand, positions).expect("Candidate moves are available moves");
+            points(game, *hand, positions) + best_points(&next, self.depth.saturating_sub(1))
+        })
+    }
+}
+
+/// Create the strategy with a name from `STRATEGIES`, or None if no strategy
+/// has the name
+pub fn strategy_named<T: Scorer + Clone>(name: &str) -> Option<Box<dyn Strategy<T>>> {
+    return match name {
+        "greedy" => Some(Box::new(GreedyStrategy)),
+        "random" => Some(Box::new(RandomStrategy)),
+        "solver" => Some(Box::new(LookaheadStrategy::new(2))),
+        "clear-stacks" => Some(Box::new(ClearStacksStrategy)),
+        "conserve-discards" => Some(Box::new(ConserveDiscardsStrategy)),
+        _ => None,
+    }
+}
+
+/// Most points which can be awarded over a number of moves
+fn best_points<T: Scorer + Clone>(game: &Game<T>, depth: usize) -> i32 {
+    if depth == 0 || game.board.count_all_cards() == 0 {
+        return 0
+    }
+    let moves = candidate_moves(game);
+    if moves.is_empty() {
+        return -DEAD_END_PENALTY
+    }
+    return moves.iter().map(|(hand, positions)| {
+        let mut next = game.clone();
+        next.play(*hand, positions).expect("Candidate moves are available moves");
+        points(game, *hand, positions) + best_points(&next, depth - 1)
+    }).max().unwrap()
+}
+
+/// Moves available on a game, excluding discards unless no hand can be played
+fn candidate_moves<T: Scorer>(game: &Game<T>) -> Vec<(MoveType, Vec<Position>)> {
+    let moves = game.available_moves();
+    if moves.iter().any(|(hand, _)| *hand != MoveType::Trash) {
+        return moves.into_iter().filter(|(hand, _)| *hand != MoveType::Trash).collect()
+    }
+    return moves
+}
+
+/// Points awarded for playing a move on the current board
+fn points<T: Scorer>(game: &Game<T>, hand: MoveType, positions: &Vec<Position>) -> i32 {
+    return game.check_score(hand, positions).map(|score| score.total()).unwrap_or(0)
+}
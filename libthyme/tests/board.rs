@@ -2,6 +2,7 @@ extern crate cards;
 extern crate libthyme;
 
 use libthyme::board::*;
+use libthyme::error::ThymeError;
 use cards::card::Card;
 
 #[test]
@@ -82,6 +83,59 @@ fn has_all_positions() {
     assert_eq!(Board::new().positions().len(), 9);
 }
 
+#[test]
+fn deals_deck_in_order() {
+    let deck = full_deck();
+    let board = Board::from_deck(deck.clone()).unwrap();
+    let top_left = Position { x: HPosition::Left, y: VPosition::Top };
+    let bottom_right = Position { x: HPosition::Right, y: VPosition::Bottom };
+    assert_eq!(board.count_all_cards(), 51);
+    assert_eq!(board.top(top_left), Some(deck[7]));
+    assert_eq!(board.top(bottom_right), Some(deck[50]));
+    assert_eq!(board.lucky_card, deck[51]);
+}
+
+#[test]
+fn rejects_short_deck() {
+    let mut deck = full_deck();
+    deck.pop();
+    assert_eq!(Board::from_deck(deck).err(), Some(ThymeError::Board(BoardError::WrongDeckSize(51))));
+}
+
+#[test]
+fn rejects_deck_with_duplicate_card() {
+    let mut deck = full_deck();
+    deck[10] = deck[3];
+    assert_eq!(Board::from_deck(deck.clone()).err(), Some(ThymeError::Board(BoardError::DuplicateCard(deck[3]))));
+}
+
+#[test]
+fn rejects_layout_with_missing_stack() {
+    let board = Board::from_deck(full_deck()).unwrap();
+    let mut stacks = board.stacks.clone();
+    let removed = stacks.remove(4);
+    let result = Board::with_stacks(stacks, board.lucky_card);
+    assert_eq!(result.err(), Some(ThymeError::Board(BoardError::MissingStack(removed.position))));
+}
+
+#[test]
+fn rejects_layout_with_duplicate_stack() {
+    let board = Board::from_deck(full_deck()).unwrap();
+    let mut stacks = board.stacks.clone();
+    let position = stacks[0].position;
+    stacks.push(Stack { position: position, cards: vec![] });
+    let result = Board::with_stacks(stacks, board.lucky_card);
+    assert_eq!(result.err(), Some(ThymeError::Board(BoardError::DuplicateStack(position))));
+}
+
+#[test]
+fn rejects_layout_repeating_lucky_card() {
+    let board = Board::from_deck(full_deck()).unwrap();
+    let lucky_card = board.stacks[0].cards[0];
+    let result = Board::with_stacks(board.stacks.clone(), lucky_card);
+    assert_eq!(result.err(), Some(ThymeError::Board(BoardError::DuplicateCard(lucky_card))));
+}
+
 fn all_cards(mut board: Board) -> Vec<Card> {
     let mut stack = Vec::<Card>::new();
     stack.extend(&mut empty_stack(&mut board, HPosition::Left, VPosition::Top).iter().cloned());
@@ -96,6 +150,11 @@ fn all_cards(mut board: Board) -> Vec<Card> {
     return stack;
 }
 
+fn full_deck() -> Vec<Card> {
+    let mut deck = cards::deck::Deck::new_unshuffled();
+    return deck.draw_n(DECK_SIZE).ok().unwrap();
+}
+
 fn empty_stack(board: &mut Board, x: HPosition, y: VPosition) -> Vec<Card> {
     let mut stack = Vec::<Card>::new();
     loop {
@@ -117,3 +176,20 @@ fn check_count(x: HPosition, y: VPosition, count: usize) {
     }
     assert!(board.pop(&vec![Position { x: x, y: y }]).is_none());
 }
+
+#[test]
+fn deals_same_board_from_same_seed() {
+    let board = Board::with_seed(42);
+    assert_eq!(board.count_all_cards(), 51);
+    assert_eq!(stack_tops(&board), stack_tops(&Board::with_seed(42)));
+    assert_eq!(board.lucky_card, Board::with_seed(42).lucky_card);
+}
+
+#[test]
+fn deals_different_boards_from_different_seeds() {
+    assert!(stack_tops(&Board::with_seed(1)) != stack_tops(&Board::with_seed(2)));
+}
+
+fn stack_tops(board: &Board) -> Vec<Option<Card>> {
+    return board.positions().iter().map(|p| board.top(*p)).collect()
+}
@@ -3,6 +3,7 @@ extern crate libthyme;
 
 use cards::card::{Card, Suit, Value};
 use libthyme::card::*;
+use libthyme::game::MoveType;
 
 #[test]
 fn single_card_is_not_same_suit() {
@@ -178,3 +179,89 @@ fn contains_other_multiple_multiples_is_match() {
         Card { value: Value::King, suit: Suit::Spades }];
     assert!(contains_multiple_of_value(&hand, 2));
 }
+
+#[test]
+fn classify_straight_flush_as_every_hand() {
+    let hand = vec![
+        Card { value: Value::Five, suit: Suit::Clubs },
+        Card { value: Value::Nine, suit: Suit::Clubs },
+        Card { value: Value::Six, suit: Suit::Clubs },
+        Card { value: Value::Eight, suit: Suit::Clubs },
+        Card { value: Value::Seven, suit: Suit::Clubs }];
+    assert_eq!(classify(&hand), vec![
+        MoveType::StraightFlush, MoveType::Flush, MoveType::FiveCardStraight]);
+}
+
+#[test]
+fn classify_full_house() {
+    let hand = vec![
+        Card { value: Value::Five, suit: Suit::Hearts },
+        Card { value: Value::Five, suit: Suit::Diamonds },
+        Card { value: Value::Five, suit: Suit::Clubs },
+        Card { value: Value::Eight, suit: Suit::Diamonds },
+        Card { value: Value::Eight, suit: Suit::Clubs }];
+    assert_eq!(classify(&hand), vec![MoveType::FullHouse]);
+}
+
+#[test]
+fn classify_single_card_as_trash() {
+    let hand = vec![Card { value: Value::Ace, suit: Suit::Spades }];
+    assert_eq!(classify(&hand), vec![MoveType::Trash]);
+}
+
+#[test]
+fn classify_non_hand_as_nothing() {
+    let hand = vec![
+        Card { value: Value::Ace, suit: Suit::Spades },
+        Card { value: Value::Ace, suit: Suit::Hearts },
+        Card { value: Value::King, suit: Suit::Clubs },
+        Card { value: Value::King, suit: Suit::Spades }];
+    assert!(classify(&hand).is_empty());
+}
+
+#[test]
+fn two_pair_is_two_pair() {
+    let hand = vec![
+        Card { value: Value::Ace, suit: Suit::Spades },
+        Card { value: Value::Ace, suit: Suit::Hearts },
+        Card { value: Value::King, suit: Suit::Clubs },
+        Card { value: Value::King, suit: Suit::Spades }];
+    assert!(is_two_pair(&hand));
+}
+
+#[test]
+fn four_of_a_kind_is_not_two_pair() {
+    let hand = vec![
+        Card { value: Value::Ace, suit: Suit::Spades },
+        Card { value: Value::Ace, suit: Suit::Hearts },
+        Card { value: Value::Ace, suit: Suit::Clubs },
+        Card { value: Value::Ace, suit: Suit::Diamonds }];
+    assert!(!is_two_pair(&hand));
+}
+
+#[test]
+fn ace_low_straight_missing_value_has_single_gap() {
+    let hand = vec![
+        Card { value: Value::Ace, suit: Suit::Spades },
+        Card { value: Value::Three, suit: Suit::Hearts },
+        Card { value: Value::Four, suit: Suit::Clubs }];
+    assert!(has_single_gap(&hand));
+}
+
+#[test]
+fn straight_missing_two_values_has_no_single_gap() {
+    let hand = vec![
+        Card { value: Value::Four, suit: Suit::Spades },
+        Card { value: Value::Five, suit: Suit::Hearts },
+        Card { value: Value::Eight, suit: Suit::Clubs }];
+    assert!(!has_single_gap(&hand));
+}
+
+#[test]
+fn consecutive_has_no_single_gap() {
+    let hand = vec![
+        Card { value: Value::Four, suit: Suit::Spades },
+        Card { value: Value::Five, suit: Suit::Hearts },
+        Card { value: Value::Six, suit: Suit::Clubs }];
+    assert!(!has_single_gap(&hand));
+}
@@ -0,0 +1,68 @@
+extern crate cards;
+extern crate libthyme;
+
+use cards::card::{Card, Suit, Value};
+use libthyme::card_code::*;
+
+#[test]
+fn parses_ascii_codes() {
+    assert_eq!(parse("10H"), Some(Card { value: Value::Ten, suit: Suit::Hearts }));
+    assert_eq!(parse("TH"), Some(Card { value: Value::Ten, suit: Suit::Hearts }));
+    assert_eq!(parse("AD"), Some(Card { value: Value::Ace, suit: Suit::Diamonds }));
+}
+
+#[test]
+fn parses_lowercase_codes() {
+    assert_eq!(parse("10h"), Some(Card { value: Value::Ten, suit: Suit::Hearts }));
+    assert_eq!(parse("qs"), Some(Card { value: Value::Queen, suit: Suit::Spades }));
+}
+
+#[test]
+fn parses_unicode_codes() {
+    assert_eq!(parse("A\u{2660}"), Some(Card { value: Value::Ace, suit: Suit::Spades }));
+    assert_eq!(parse("7\u{2663}"), Some(Card { value: Value::Seven, suit: Suit::Clubs }));
+    assert_eq!(parse("j\u{2661}"), Some(Card { value: Value::Jack, suit: Suit::Hearts }));
+}
+
+#[test]
+fn rejects_invalid_codes() {
+    assert_eq!(parse("1H"), None);
+    assert_eq!(parse("AX"), None);
+    assert_eq!(parse("H"), None);
+    assert_eq!(parse(""), None);
+}
+
+#[test]
+fn parses_lists_of_codes() {
+    assert_eq!(parse_all("5C 6c  7\u{2663}"), Some(vec![
+        Card { value: Value::Five, suit: Suit::Clubs },
+        Card { value: Value::Six, suit: Suit::Clubs },
+        Card { value: Value::Seven, suit: Suit::Clubs }]));
+    assert_eq!(parse_all("5C 6X"), None);
+}
+
+#[test]
+fn formats_ascii_codes() {
+    assert_eq!(format(Card { value: Value::Ten, suit: Suit::Hearts }, CardStyle::Ascii), "10H");
+    assert_eq!(format(Card { value: Value::Seven, suit: Suit::Clubs }, CardStyle::Ascii), "7C");
+}
+
+#[test]
+fn formats_unicode_codes() {
+    assert_eq!(format(Card { value: Value::Queen, suit: Suit::Diamonds }, CardStyle::Unicode), "Q\u{2666}");
+    assert_eq!(format(Card { value: Value::Ace, suit: Suit::Spades }, CardStyle::Unicode), "A\u{2660}");
+}
+
+#[test]
+fn formats_lists_of_codes() {
+    let cards = parse_all("10S JS QS").unwrap();
+    assert_eq!(format_all(&cards, CardStyle::Ascii), "10S JS QS");
+}
+
+#[test]
+fn formatted_codes_round_trip() {
+    for card in parse_all("2S 3H 4D 5C 6S 7H 8D 9C 10S JH QD KC AS").unwrap() {
+        assert_eq!(parse(&format(card, CardStyle::Ascii)), Some(card));
+        assert_eq!(parse(&format(card, CardStyle::Unicode)), Some(card));
+    }
+}
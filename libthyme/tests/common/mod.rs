@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use cards::card::{Card, Suit, Value};
+use libthyme::board::*;
+use libthyme::game::Game;
+use libthyme::score::Scorer;
+
+/// Positions of the cards given to `game_with_cards`, in order
+pub fn card_positions(count: usize) -> Vec<Position> {
+    let mut positions = vec![
+        Position { x: HPosition::Left, y: VPosition::Top },
+        Position { x: HPosition::Center, y: VPosition::Middle },
+        Position { x: HPosition::Right, y: VPosition::Top },
+        Position { x: HPosition::Left, y: VPosition::Middle },
+        Position { x: HPosition::Center, y: VPosition::Top }];
+    positions.truncate(count);
+    return positions
+}
+
+/// Create a game with each card on its own stack, at `card_positions`
+pub fn game_with_cards<T: Scorer>(cards: Vec<Card>, discards: i32) -> Game<T> {
+    let positions = card_positions(cards.len());
+    return setup_game(cards, &positions, discards)
+}
+
+/// Create a game with each card on its own stack at a position, beside
+/// spades on the bottom row and right of the middle row which make no hand
+/// on their own
+pub fn setup_game<T: Scorer>(cards: Vec<Card>, positions: &Vec<Position>, discards: i32) -> Game<T> {
+    let mut stacks = Vec::new();
+    for index in 0..cards.len() {
+        stacks.push(Stack { cards: vec![cards[index]], position: positions[index] });
+    }
+    stacks.push(Stack {
+        cards: vec![Card { value: Value::Ace, suit: Suit::Spades }],
+        position: Position { x: HPosition::Left, y: VPosition::Bottom } });
+    stacks.push(Stack {
+        cards: vec![Card { value: Value::Jack, suit: Suit::Spades }],
+        position: Position { x: HPosition::Center, y: VPosition::Bottom } });
+    stacks.push(Stack {
+        cards: vec![Card { value: Value::King, suit: Suit::Spades }],
+        position: Position { x: HPosition::Right, y: VPosition::Bottom } });
+    stacks.push(Stack {
+        cards: vec![Card { value: Value::Seven, suit: Suit::Spades }],
+        position: Position { x: HPosition::Right, y: VPosition::Middle } });
+    let lucky_card = Card { value: Value::King, suit: Suit::Hearts };
+    let board = Board { stacks: stacks, lucky_card: lucky_card };
+    let mut game = Game::with_board(board);
+    game.discards_allowed = discards;
+    game.discards_allowed_max = discards;
+    return game;
+}
+
@@ -0,0 +1,100 @@
+extern crate libthyme;
+
+use libthyme::board::*;
+use libthyme::env::*;
+use libthyme::game::*;
+
+#[test]
+fn reset_deals_same_game_from_seed() {
+    let mut env = Env::new(1);
+    let observation = env.reset(7);
+    assert_eq!(observation.len(), OBSERVATION_SIZE);
+    assert_eq!(observation, Env::new(7).observation());
+    assert!(observation != Env::new(8).observation());
+}
+
+#[test]
+fn observation_encodes_board() {
+    let env = Env::new(7);
+    let observation = env.observation();
+    let tops: f32 = observation[..9*DECK_SIZE].iter().sum();
+    assert_eq!(tops, 9.0);
+    assert_eq!(observation[9*DECK_SIZE], 1.0);
+    assert_eq!(observation[9*DECK_SIZE + 8], 0.25);
+    assert_eq!(observation[9*DECK_SIZE + 9], 1.0);
+    let suits: f32 = observation[9*DECK_SIZE + 10..].iter().sum();
+    assert_eq!(suits, 1.0);
+}
+
+#[test]
+fn observation_is_finite_without_discards() {
+    let mut env = Env::new(7);
+    env.game.discards_allowed = 0;
+    env.game.discards_allowed_max = 0;
+    let observation = env.observation();
+    assert!(observation.iter().all(|value| value.is_finite()));
+    assert_eq!(observation[9*DECK_SIZE + 9], 0.0);
+}
+
+#[test]
+fn actions_round_trip() {
+    assert_eq!(ACTION_COUNT, 511*9);
+    for action in 0..ACTION_COUNT {
+        let (hand, positions) = decode_action(action).unwrap();
+        assert_eq!(encode_action(hand, &positions), action);
+    }
+    assert_eq!(decode_action(ACTION_COUNT), None);
+}
+
+#[test]
+fn decodes_positions_and_hand() {
+    let (hand, positions) = decode_action(0).unwrap();
+    assert_eq!(hand, MoveType::StraightFlush);
+    assert_eq!(positions, vec![POSITIONS[0]]);
+    let (hand, positions) = decode_action(ACTION_COUNT - 1).unwrap();
+    assert_eq!(hand, MoveType::Trash);
+    assert_eq!(positions.len(), 9);
+}
+
+#[test]
+fn mask_allows_available_moves() {
+    let env = Env::new(7);
+    let mask = env.action_mask();
+    assert_eq!(mask.len(), ACTION_COUNT);
+    assert_eq!(mask.iter().filter(|legal| **legal).count(), env.game.available_moves().len());
+    let trash = encode_action(MoveType::Trash, &vec![POSITIONS[0]]);
+    assert!(mask[trash]);
+}
+
+#[test]
+fn step_plays_legal_action() {
+    let mut env = Env::new(7);
+    let action = env.action_mask().iter().position(|legal| *legal).unwrap();
+    let before = env.game.score();
+    let (observation, reward, done) = env.step(action).unwrap();
+    assert_eq!(reward, env.game.score() - before);
+    assert_eq!(observation, env.observation());
+    assert_eq!(env.game.plays.len(), 1);
+    assert!(!done);
+}
+
+#[test]
+fn step_rejects_illegal_action() {
+    let mut env = Env::new(7);
+    let action = env.action_mask().iter().position(|legal| !*legal).unwrap();
+    assert!(env.step(action).is_err());
+    assert_eq!(env.step(ACTION_COUNT), Err(MoveError::InvalidMove));
+    assert!(env.game.plays.is_empty());
+}
+
+#[test]
+fn steps_until_done() {
+    let mut env = Env::new(7);
+    let mut done = false;
+    while !done {
+        let action = env.action_mask().iter().position(|legal| *legal).unwrap();
+        done = env.step(action).unwrap().2;
+    }
+    assert!(env.game.is_finished());
+    assert!(env.action_mask().iter().all(|legal| !*legal));
+}
@@ -0,0 +1,62 @@
+extern crate cards;
+extern crate libthyme;
+
+use cards::card::{Card, Suit, Value};
+use libthyme::board::*;
+use libthyme::error::ThymeError;
+use libthyme::game::{Game,MoveError,MoveType};
+use libthyme::notation::NotationError;
+use libthyme::score::{Play,StandardScorer};
+use std::error::Error;
+
+#[test]
+fn describes_move_errors() {
+    assert_eq!(MoveError::NeedMultipleRows.to_string(), "A hand must use cards from more than one row");
+    assert_eq!(MoveError::OutOfTime.to_string(), "Game Over - Out of time");
+}
+
+#[test]
+fn describes_board_errors() {
+    let card = Card { value: Value::Ten, suit: Suit::Hearts };
+    let position = Position { x: HPosition::Center, y: VPosition::Bottom };
+    assert_eq!(BoardError::WrongDeckSize(50).to_string(), "A deck must have 52 cards, not 50");
+    assert_eq!(BoardError::DuplicateCard(card).to_string(), "10H is dealt more than once");
+    assert_eq!(BoardError::MissingStack(position).to_string(), "No stack is at BC");
+}
+
+#[test]
+fn describes_notation_errors() {
+    let error = NotationError::InvalidCard("1H".to_string());
+    assert_eq!(error.to_string(), "'1H' is not a card");
+    let error = NotationError::DuplicateLabel("TL".to_string());
+    assert_eq!(error.to_string(), "'TL' is given more than once");
+}
+
+#[test]
+fn wraps_errors() {
+    let error = ThymeError::from(MoveError::StraightHasGap);
+    assert_eq!(error, ThymeError::Move(MoveError::StraightHasGap));
+    assert_eq!(error.to_string(), "A straight cannot skip a value");
+    assert!(error.source().is_none());
+    let error = ThymeError::from(BoardError::WrongDeckSize(0));
+    assert_eq!(error.to_string(), "A deck must have 52 cards, not 0");
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn converts_with_question_mark() {
+    fn play(notation: &str) -> Result<Play, ThymeError> {
+        let mut game: Game<StandardScorer> = notation.parse()?;
+        let positions = vec![
+            Position { x: HPosition::Left, y: VPosition::Top },
+            Position { x: HPosition::Right, y: VPosition::Top }];
+        return Ok(game.play(MoveType::Pair, &positions)?)
+    }
+    let result = play("TL: 2S");
+    assert_eq!(result.err(), Some(ThymeError::Notation(NotationError::MissingLuckyCard)));
+    let result = play("TL: 2S; MC: 2S; lucky: KH");
+    let card = Card { value: Value::Two, suit: Suit::Spades };
+    assert_eq!(result.err(), Some(ThymeError::Board(BoardError::DuplicateCard(card))));
+    let result = play("TL: 2S; TR: 2H; lucky: KH");
+    assert_eq!(result.err(), Some(ThymeError::Move(MoveError::NeedMultipleRows)));
+}
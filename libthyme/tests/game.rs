@@ -5,6 +5,11 @@ use libthyme::game::*;
 use libthyme::board::*;
 use libthyme::score::*;
 use cards::card::{Card, Suit, Value};
+use std::time::{Duration, Instant};
+
+mod common;
+
+use common::{card_positions,game_with_cards,setup_game};
 
 #[test]
 fn check_straight_flush() {
@@ -108,6 +113,248 @@ fn check_invalid() {
     assert_eq!(check.err().unwrap(), MoveError::InvalidMove);
 }
 
+#[test]
+fn repeated_positions_are_invalid() {
+    let mut game: Game<StandardScorer> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+    let top_left = Position { x: HPosition::Left, y: VPosition::Top };
+    let positions = vec![
+        top_left,
+        Position { x: HPosition::Center, y: VPosition::Middle },
+        top_left,
+        top_left];
+    assert_eq!(game.check(&positions).err().unwrap(), MoveError::InvalidMove);
+    assert_eq!(game.check_score(MoveType::Pair, &positions).err().unwrap(), MoveError::InvalidMove);
+    assert_eq!(game.play(MoveType::Pair, &positions).err().unwrap(), MoveError::InvalidMove);
+    assert_eq!(game.score(), 0);
+    assert_eq!(game.board.count_all_cards(), 2);
+}
+
+#[test]
+fn check_two_pair() {
+    let check = check_game(vec![
+        Card { value: Value::Six, suit: Suit::Hearts },
+        Card { value: Value::Six, suit: Suit::Clubs },
+        Card { value: Value::Nine, suit: Suit::Hearts },
+        Card { value: Value::Nine, suit: Suit::Clubs }], 0);
+    assert_eq!(check.err().unwrap(), MoveError::TwoPairIsInvalid);
+}
+
+#[test]
+fn check_four_card_flush() {
+    let check = check_game(vec![
+        Card { value: Value::Two, suit: Suit::Hearts },
+        Card { value: Value::Six, suit: Suit::Hearts },
+        Card { value: Value::Nine, suit: Suit::Hearts },
+        Card { value: Value::Queen, suit: Suit::Hearts }], 0);
+    assert_eq!(check.err().unwrap(), MoveError::FlushNeedsFiveCards);
+}
+
+#[test]
+fn check_straight_with_gap() {
+    let check = check_game(vec![
+        Card { value: Value::Five, suit: Suit::Hearts },
+        Card { value: Value::Four, suit: Suit::Diamonds },
+        Card { value: Value::Seven, suit: Suit::Clubs }], 0);
+    assert_eq!(check.err().unwrap(), MoveError::StraightHasGap);
+}
+
+#[test]
+fn play_straight_flush_as_flush() {
+    let result = play_game(MoveType::Flush, vec![
+        Card { value: Value::Five, suit: Suit::Clubs },
+        Card { value: Value::Nine, suit: Suit::Clubs },
+        Card { value: Value::Six, suit: Suit::Clubs },
+        Card { value: Value::Eight, suit: Suit::Clubs },
+        Card { value: Value::Seven, suit: Suit::Clubs }], 0);
+    assert_eq!(result.ok().unwrap().hand, MoveType::Flush);
+}
+
+#[test]
+fn play_mismatched_hand() {
+    let result = play_game(MoveType::Pair, vec![
+        Card { value: Value::Five, suit: Suit::Clubs },
+        Card { value: Value::Nine, suit: Suit::Clubs },
+        Card { value: Value::Six, suit: Suit::Clubs },
+        Card { value: Value::Eight, suit: Suit::Clubs },
+        Card { value: Value::Seven, suit: Suit::Clubs }], 0);
+    assert_eq!(result.err().unwrap(), MoveError::InvalidHand);
+}
+
+#[test]
+fn play_records_play() {
+    let positions = vec![
+        Position { x: HPosition::Left, y: VPosition::Top },
+        Position { x: HPosition::Center, y: VPosition::Middle }];
+    let mut game = setup_game::<StandardScorer>(vec![
+        Card { value: Value::Six, suit: Suit::Hearts },
+        Card { value: Value::Six, suit: Suit::Clubs }], &positions, 0);
+    let points = game.check_score(MoveType::Pair, &positions).ok().unwrap().total();
+    game.play(MoveType::Pair, &positions).ok().unwrap();
+    assert_eq!(game.plays.len(), 1);
+    assert_eq!(game.plays[0].hand, MoveType::Pair);
+    assert_eq!(game.score(), points);
+}
+
+#[test]
+fn card_status_tracks_plays() {
+    let positions = vec![
+        Position { x: HPosition::Left, y: VPosition::Top },
+        Position { x: HPosition::Center, y: VPosition::Middle }];
+    let six_of_hearts = Card { value: Value::Six, suit: Suit::Hearts };
+    let six_of_clubs = Card { value: Value::Six, suit: Suit::Clubs };
+    let mut game = setup_game::<StandardScorer>(vec![six_of_hearts, six_of_clubs], &positions, 1);
+    game.board.stacks[0].cards.insert(0, Card { value: Value::Two, suit: Suit::Clubs });
+    assert_eq!(game.card_status(six_of_hearts), CardStatus::Visible);
+    assert_eq!(game.card_status(Card { value: Value::Two, suit: Suit::Clubs }), CardStatus::Buried);
+    assert_eq!(game.card_status(Card { value: Value::King, suit: Suit::Hearts }), CardStatus::Lucky);
+    assert_eq!(game.card_status(Card { value: Value::Two, suit: Suit::Hearts }), CardStatus::Absent);
+    game.play(MoveType::Pair, &positions).ok().unwrap();
+    assert_eq!(game.card_status(six_of_hearts), CardStatus::Played);
+    assert_eq!(game.card_status(six_of_clubs), CardStatus::Played);
+    game.play(MoveType::Trash, &vec![positions[0]]).ok().unwrap();
+    assert_eq!(game.card_status(Card { value: Value::Two, suit: Suit::Clubs }), CardStatus::Trashed);
+}
+
+#[test]
+fn timed_game_rejects_play_out_of_time() {
+    let mut game: Game<StandardScorer> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+    game.time_limit = Some(Duration::from_secs(60));
+    game.started = Instant::now() - Duration::from_secs(90);
+    assert!(game.is_out_of_time());
+    assert_eq!(game.elapsed(), Duration::from_secs(60));
+    assert_eq!(game.time_remaining(), Some(Duration::from_secs(0)));
+    let positions = vec![
+        Position { x: HPosition::Left, y: VPosition::Top },
+        Position { x: HPosition::Center, y: VPosition::Middle }];
+    assert_eq!(game.play(MoveType::Pair, &positions).err().unwrap(), MoveError::OutOfTime);
+}
+
+#[test]
+fn clearing_board_stops_clock_and_awards_time_bonus() {
+    let mut game: Game<StandardScorer> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+    game.time_limit = Some(Duration::from_secs(60));
+    // Pause the clock at a fixed instant so exactly 20.5 seconds have passed
+    let now = Instant::now();
+    game.started = now - Duration::from_millis(20500);
+    game.paused = Some(now);
+    let positions = vec![
+        Position { x: HPosition::Left, y: VPosition::Top },
+        Position { x: HPosition::Center, y: VPosition::Middle }];
+    let points = game.check_score(MoveType::Pair, &positions).ok().unwrap().total();
+    game.play(MoveType::Pair, &positions).ok().unwrap();
+    assert_eq!(game.stopped, Some(Duration::from_millis(20500)));
+    assert_eq!(game.elapsed(), Duration::from_millis(20500));
+    assert_eq!(game.score(), points + 39 * 2);
+}
+
+/// Counts plays, without a time bonus
+struct PlayCounter {
+    plays: i32,
+}
+
+impl Scorer for PlayCounter {
+
+    fn new(_lucky_card: Card) -> Self {
+        return PlayCounter { plays: 0 }
+    }
+
+    fn check_play(&self, _play: Play) -> Score {
+        return Score { value: 1, bonus: 0, multiplier: 1 }
+    }
+
+    fn add_play(&mut self, _play: Play) {
+        self.plays += 1;
+    }
+
+    fn bonus(&self, _position: Position) -> i32 {
+        return 0
+    }
+
+    fn score(&self, _completion: bool) -> i32 {
+        return self.plays
+    }
+
+    fn format_as_score(&self, value: i32) -> String {
+        return value.to_string()
+    }
+}
+
+#[test]
+fn scorer_without_time_bonus_scores_plays() {
+    let mut game: Game<PlayCounter> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+    game.time_limit = Some(Duration::from_secs(60));
+    let positions = vec![
+        Position { x: HPosition::Left, y: VPosition::Top },
+        Position { x: HPosition::Center, y: VPosition::Middle }];
+    game.play(MoveType::Pair, &positions).ok().unwrap();
+    assert_eq!(game.status, GameStatus::Won);
+    assert_eq!(game.score(), 1);
+}
+
+#[test]
+fn pause_stops_clock() {
+    let mut game: Game<StandardScorer> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+    game.started = Instant::now() - Duration::from_secs(30);
+    game.pause();
+    let paused = game.elapsed();
+    game.paused = Some(game.paused.unwrap() - Duration::from_secs(20));
+    game.resume();
+    assert!(game.paused.is_none());
+    assert!(game.elapsed() < paused);
+    assert!(game.elapsed() >= Duration::from_secs(10));
+}
+
+#[test]
+fn untimed_game_has_no_time_limit() {
+    let game: Game<StandardScorer> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+    assert!(game.time_remaining().is_none());
+    assert!(!game.is_out_of_time());
+    assert!(game.stopped.is_none());
+}
+
+#[test]
+fn clearing_board_wins() {
+    let mut game: Game<StandardScorer> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+    assert_eq!(game.status, GameStatus::InProgress);
+    let positions = vec![
+        Position { x: HPosition::Left, y: VPosition::Top },
+        Position { x: HPosition::Center, y: VPosition::Middle }];
+    game.play(MoveType::Pair, &positions).ok().unwrap();
+    assert_eq!(game.status, GameStatus::Won);
+    assert!(game.is_finished());
+}
+
+#[test]
+fn running_out_of_moves_loses() {
+    let mut game: Game<StandardScorer> = "TL: 6H; MC: 6C; BR: 2S 9D; lucky: KH; discards: 0/0".parse().unwrap();
+    let positions = vec![
+        Position { x: HPosition::Left, y: VPosition::Top },
+        Position { x: HPosition::Center, y: VPosition::Middle }];
+    game.play(MoveType::Pair, &positions).ok().unwrap();
+    assert_eq!(game.status, GameStatus::Lost { cards_left: 2 });
+    assert!(game.stopped.is_some());
+}
+
+#[test]
+fn play_is_rejected_after_game_finishes() {
+    let mut game: Game<StandardScorer> = "TL: 6H; MC: 6C; BR: 2S 9D; lucky: KH; discards: 0/0".parse().unwrap();
+    game.status = GameStatus::Lost { cards_left: 4 };
+    let positions = vec![
+        Position { x: HPosition::Left, y: VPosition::Top },
+        Position { x: HPosition::Center, y: VPosition::Middle }];
+    assert_eq!(game.play(MoveType::Pair, &positions).err().unwrap(), MoveError::GameFinished);
+}
+
+#[test]
+fn update_status_reports_time_running_out() {
+    let mut game: Game<StandardScorer> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+    assert_eq!(game.update_status(), None);
+    game.time_limit = Some(Duration::from_secs(60));
+    game.started = Instant::now() - Duration::from_secs(90);
+    assert_eq!(game.update_status(), Some(GameStatus::Lost { cards_left: 2 }));
+    assert_eq!(game.update_status(), None);
+}
+
 #[test]
 fn test_moves_remain_with_cards() {
     assert!(check_moves(vec![
@@ -127,50 +374,15 @@ fn test_no_moves_remain_with_cards() {
 }
 
 fn check_moves(cards: Vec<Card>, discards: i32) -> bool {
-    let mut positions = vec![
-        Position { x: HPosition::Left, y: VPosition::Top },
-        Position { x: HPosition::Center, y: VPosition::Middle },
-        Position { x: HPosition::Right, y: VPosition::Top },
-        Position { x: HPosition::Left, y: VPosition::Middle },
-        Position { x: HPosition::Center, y: VPosition::Top }];
-    positions.truncate(cards.len());
-    return setup_game::<StandardScorer>(cards, &positions, discards).moves_remaining()
+    return game_with_cards::<StandardScorer>(cards, discards).moves_remaining()
 }
 
 fn check_game(cards: Vec<Card>, discards: i32) -> Result<MoveType, MoveError> {
-    let mut positions = vec![
-        Position { x: HPosition::Left, y: VPosition::Top },
-        Position { x: HPosition::Center, y: VPosition::Middle },
-        Position { x: HPosition::Right, y: VPosition::Top },
-        Position { x: HPosition::Left, y: VPosition::Middle },
-        Position { x: HPosition::Center, y: VPosition::Top }];
-    positions.truncate(cards.len());
-    return setup_game::<StandardScorer>(cards, &positions, discards).check(&positions);
+    let positions = card_positions(cards.len());
+    return game_with_cards::<StandardScorer>(cards, discards).check(&positions);
 }
 
-fn setup_game<T: Scorer>(cards: Vec<Card>, positions: &Vec<Position>, discards: i32) -> Game<T> {
-    let mut stacks = Vec::new();
-    for index in 0..cards.len() {
-        stacks.push(Stack { cards: vec![cards[index]], position: positions[index] });
-    }
-    stacks.push(Stack {
-        cards: vec![Card { value: Value::Ace, suit: Suit::Spades }],
-        position: Position { x: HPosition::Left, y: VPosition::Bottom } });
-    stacks.push(Stack {
-        cards: vec![Card { value: Value::Jack, suit: Suit::Spades }],
-        position: Position { x: HPosition::Center, y: VPosition::Bottom } });
-    stacks.push(Stack {
-        cards: vec![Card { value: Value::King, suit: Suit::Spades }],
-        position: Position { x: HPosition::Right, y: VPosition::Bottom } });
-    stacks.push(Stack {
-        cards: vec![Card { value: Value::Seven, suit: Suit::Spades }],
-        position: Position { x: HPosition::Right, y: VPosition::Middle } });
-    let lucky_card = Card { value: Value::King, suit: Suit::Hearts };
-    let board = Board { stacks: stacks, lucky_card: lucky_card };
-    return Game {
-        board: board,
-        discards_allowed: discards,
-        discards_allowed_max: discards,
-        scorer: Scorer::new(lucky_card) };
+fn play_game(hand: MoveType, cards: Vec<Card>, discards: i32) -> Result<Play, MoveError> {
+    let positions = card_positions(cards.len());
+    return game_with_cards::<StandardScorer>(cards, discards).play(hand, &positions);
 }
-
@@ -0,0 +1,101 @@
+extern crate cards;
+extern crate libthyme;
+
+use cards::card::{Card, Suit, Value};
+use libthyme::board::*;
+use libthyme::error::ThymeError;
+use libthyme::game::Game;
+use libthyme::notation::*;
+use libthyme::score::StandardScorer;
+
+#[test]
+fn board_rejects_invalid_card() {
+    let result = "TL: 2S 1H\nlucky: KH".parse::<Board>();
+    assert_eq!(result.err(), Some(ThymeError::Notation(NotationError::InvalidCard("1H".to_string()))));
+}
+
+#[test]
+fn parses_board() {
+    let board: Board = "TL: 2S 5H\nBR: 10C\nlucky: KH".parse().unwrap();
+    let top_left = Position { x: HPosition::Left, y: VPosition::Top };
+    let bottom_right = Position { x: HPosition::Right, y: VPosition::Bottom };
+    assert_eq!(board.positions().len(), 9);
+    assert_eq!(board.count_all_cards(), 3);
+    assert_eq!(board.top(top_left), Some(Card { value: Value::Five, suit: Suit::Hearts }));
+    assert_eq!(board.top(bottom_right), Some(Card { value: Value::Ten, suit: Suit::Clubs }));
+    assert_eq!(board.lucky_card, Card { value: Value::King, suit: Suit::Hearts });
+}
+
+#[test]
+fn parses_board_on_one_line() {
+    let board: Board = "TL: 2S; MC: 3S; lucky: KH".parse().unwrap();
+    assert_eq!(board.count_all_cards(), 2);
+}
+
+#[test]
+fn board_requires_lucky_card() {
+    assert_eq!("TL: 2S".parse::<Board>().err(), Some(ThymeError::Notation(NotationError::MissingLuckyCard)));
+}
+
+#[test]
+fn board_rejects_unknown_label() {
+    let result = "XX: 2S\nlucky: KH".parse::<Board>();
+    assert_eq!(result.err(), Some(ThymeError::Notation(NotationError::InvalidLabel("XX".to_string()))));
+}
+
+#[test]
+fn board_round_trips() {
+    let board = Board::new();
+    let notation = board.to_string();
+    assert_eq!(notation.parse::<Board>().unwrap().to_string(), notation);
+}
+
+#[test]
+fn parses_game_discards() {
+    let game: Game<StandardScorer> = "TL: 2S\nMC: 2H\nlucky: KH\ndiscards: 1/3".parse().unwrap();
+    assert_eq!(game.discards_allowed, 1);
+    assert_eq!(game.discards_allowed_max, 3);
+    assert_eq!(game.board.count_all_cards(), 2);
+}
+
+#[test]
+fn game_rejects_invalid_discards() {
+    for discards in ["-1/2", "3/2", "1/-1", "1", "a/b"].iter() {
+        let result = format!("TL: 2S\nlucky: KH\ndiscards: {}", discards).parse::<Game<StandardScorer>>();
+        assert_eq!(result.err(), Some(ThymeError::Notation(NotationError::InvalidDiscards(discards.to_string()))));
+    }
+}
+
+#[test]
+fn rejects_duplicate_labels() {
+    let result = "TL: 2S\ntl: 5H\nlucky: KH".parse::<Board>();
+    assert_eq!(result.err(), Some(ThymeError::Notation(NotationError::DuplicateLabel("tl".to_string()))));
+    let result = "TL: 2S\nlucky: KH\nLucky: QH".parse::<Board>();
+    assert_eq!(result.err(), Some(ThymeError::Notation(NotationError::DuplicateLabel("Lucky".to_string()))));
+    let result = "TL: 2S\nlucky: KH\ndiscards: 1/2\ndiscards: 2/2".parse::<Game<StandardScorer>>();
+    assert_eq!(result.err(), Some(ThymeError::Notation(NotationError::DuplicateLabel("discards".to_string()))));
+}
+
+#[test]
+fn reads_labels_in_any_case() {
+    let game: Game<StandardScorer> = "tl: 2S\nmc: 2H\nLUCKY: KH\nDiscards: 1/3".parse().unwrap();
+    assert_eq!(game.board.count_all_cards(), 2);
+    assert_eq!(game.board.lucky_card, Card { value: Value::King, suit: Suit::Hearts });
+    assert_eq!(game.discards_allowed, 1);
+}
+
+#[test]
+fn game_round_trips() {
+    let mut game = Game::<StandardScorer>::new();
+    game.discards_allowed = 1;
+    let notation = game.to_string();
+    assert!(notation.ends_with("discards: 1/2"));
+    assert_eq!(notation.parse::<Game<StandardScorer>>().unwrap().to_string(), notation);
+}
+
+#[test]
+fn board_rejects_duplicate_card() {
+    let result = "TL: 2S 5H\nBR: 2S\nlucky: KH".parse::<Board>();
+    let card = Card { value: Value::Two, suit: Suit::Spades };
+    assert_eq!(result.err(), Some(ThymeError::Board(BoardError::DuplicateCard(card))));
+}
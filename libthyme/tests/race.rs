@@ -0,0 +1,109 @@
+extern crate libthyme;
+
+use libthyme::game::*;
+use libthyme::race::*;
+use libthyme::score::*;
+use libthyme::strategy::*;
+
+#[test]
+fn starts_every_player_with_a_full_board() {
+    let race = Race::new(7, 2);
+    assert_eq!(race.seed, 7);
+    assert_eq!(race.racers.len(), 2);
+    assert_eq!(race.racers[1].name, "Player 2");
+    assert_eq!(race.racers[0].cards_remaining, race.board().count_all_cards());
+    assert!(!race.is_finished());
+}
+
+#[test]
+fn update_records_progress() {
+    let mut race = Race::new(7, 2);
+    let mut game = race_game(&race);
+    play_greedy(&mut game);
+    race.update(0, &game);
+    assert_eq!(race.racers[0].score, game.score());
+    assert_eq!(race.racers[0].cards_remaining, game.board.count_all_cards());
+    assert_eq!(race.racers[1].score, 0);
+}
+
+#[test]
+fn first_to_clear_board_wins() {
+    let mut race = Race::new(7, 3);
+    let mut game = race_game(&race);
+    race.update(0, &game);
+    game.status = GameStatus::Won;
+    race.update(2, &game);
+    race.update(1, &game);
+    assert!(race.is_finished());
+    assert_eq!(race.winner(), Some(2));
+}
+
+#[test]
+fn highest_score_wins_when_no_board_is_cleared() {
+    let mut race = Race::new(7, 2);
+    let mut high = race_game(&race);
+    play_greedy(&mut high);
+    high.status = GameStatus::Lost { cards_left: 40 };
+    let mut low = race_game(&race);
+    low.status = GameStatus::Lost { cards_left: 51 };
+    assert!(high.score() > low.score());
+    race.update(0, &low);
+    assert_eq!(race.winner(), None);
+    race.update(1, &high);
+    assert!(race.is_finished());
+    assert_eq!(race.winner(), Some(1));
+}
+
+#[test]
+fn earliest_to_finish_wins_tie() {
+    let mut race = Race::new(7, 2);
+    let mut game = race_game(&race);
+    game.status = GameStatus::Lost { cards_left: 51 };
+    race.update(1, &game);
+    race.update(0, &game);
+    assert_eq!(race.winner(), Some(1));
+}
+
+#[test]
+fn leaving_forfeits_unfinished_game() {
+    let mut race = Race::new(7, 2);
+    let mut game = race_game(&race);
+    play_greedy(&mut game);
+    race.update(0, &game);
+    race.leave(0);
+    assert!(race.racers[0].forfeited);
+    assert!(!race.is_finished());
+    let mut other = race_game(&race);
+    other.status = GameStatus::Lost { cards_left: 51 };
+    race.update(1, &other);
+    assert_eq!(race.winner(), Some(1));
+}
+
+#[test]
+fn leaving_after_finishing_keeps_result() {
+    let mut race = Race::new(7, 2);
+    let mut game = race_game(&race);
+    game.status = GameStatus::Won;
+    race.update(0, &game);
+    race.leave(0);
+    assert!(!race.racers[0].forfeited);
+    assert_eq!(race.winner(), Some(0));
+}
+
+#[test]
+fn no_winner_when_everyone_forfeits() {
+    let mut race = Race::new(7, 2);
+    race.leave(0);
+    race.leave(1);
+    assert!(race.is_finished());
+    assert_eq!(race.winner(), None);
+}
+
+fn race_game(race: &Race) -> Game<StandardScorer> {
+    return Game::with_board(race.board())
+}
+
+fn play_greedy(game: &mut Game<StandardScorer>) {
+    let (hand, positions) = GreedyStrategy.choose(game).unwrap();
+    game.play(hand, &positions).unwrap();
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "serde")]
+
+extern crate bincode;
+extern crate cards;
+extern crate libthyme;
+extern crate serde_json;
+
+use libthyme::game::*;
+use libthyme::board::*;
+use libthyme::score::*;
+use cards::card::{Card, Suit, Value};
+use std::time::{Duration, Instant};
+
+const NOTATION: &str = "TL: 2S 5H; MC: 10C 6C; BR: 6H; lucky: KH; discards: 1/2";
+
+#[test]
+fn board_round_trips_through_json() {
+    let board: Board = NOTATION.replace("; discards: 1/2", "").parse().unwrap();
+    let json = serde_json::to_string(&board).unwrap();
+    let read: Board = serde_json::from_str(&json).unwrap();
+    assert_eq!(read.to_string(), board.to_string());
+}
+
+#[test]
+fn board_round_trips_through_bincode() {
+    let board: Board = NOTATION.replace("; discards: 1/2", "").parse().unwrap();
+    let bytes = bincode::serialize(&board).unwrap();
+    let read: Board = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(read.to_string(), board.to_string());
+}
+
+#[test]
+fn cards_are_written_as_codes() {
+    let stack = Stack {
+        position: Position { x: HPosition::Left, y: VPosition::Top },
+        cards: vec![Card { value: Value::Ten, suit: Suit::Clubs }],
+    };
+    let json = serde_json::to_value(&stack).unwrap();
+    assert_eq!(json["cards"][0], "10C");
+    assert_eq!(json["position"]["x"], "Left");
+}
+
+#[test]
+fn invalid_card_is_rejected() {
+    let json = r#"{"position":{"x":"Left","y":"Top"},"cards":["1Z"]}"#;
+    assert!(serde_json::from_str::<Stack>(json).is_err());
+}
+
+#[test]
+fn board_with_invalid_layout_is_rejected() {
+    let board: Board = NOTATION.replace("; discards: 1/2", "").parse().unwrap();
+    let mut json = serde_json::to_value(&board).unwrap();
+    json["stacks"][8]["cards"][0] = "2S".into();
+    let error = serde_json::from_value::<Board>(json.clone()).err().unwrap();
+    assert_eq!(error.to_string(), "2S is dealt more than once");
+    json["stacks"][8]["cards"][0] = "KH".into();
+    assert!(serde_json::from_value::<Board>(json.clone()).is_err());
+    json["stacks"].as_array_mut().unwrap().pop();
+    let error = serde_json::from_value::<Board>(json).err().unwrap();
+    assert_eq!(error.to_string(), "No stack is at BR");
+}
+
+#[test]
+fn game_with_invalid_board_is_rejected() {
+    let game: Game<StandardScorer> = NOTATION.parse().unwrap();
+    let mut json = serde_json::to_value(&game).unwrap();
+    json["board"]["stacks"][0]["cards"][0] = "6H".into();
+    assert!(serde_json::from_value::<Game<StandardScorer>>(json).is_err());
+}
+
+#[test]
+fn game_round_trips_through_json() {
+    let mut game: Game<StandardScorer> = NOTATION.parse().unwrap();
+    game.time_limit = Some(Duration::from_secs(60));
+    game.started = Instant::now() - Duration::from_secs(10);
+    let positions = vec![
+        Position { x: HPosition::Center, y: VPosition::Middle },
+        Position { x: HPosition::Right, y: VPosition::Bottom }];
+    game.play(MoveType::Pair, &positions).ok().unwrap();
+    let json = serde_json::to_string(&game).unwrap();
+    let read: Game<StandardScorer> = serde_json::from_str(&json).unwrap();
+    assert_eq!(read.to_string(), game.to_string());
+    assert_eq!(read.score(), game.score());
+    assert_eq!(read.plays.len(), 1);
+    assert_eq!(read.plays[0].cards, game.plays[0].cards);
+    assert_eq!(read.time_limit, game.time_limit);
+    assert!(read.elapsed() >= Duration::from_secs(10));
+    assert!(read.elapsed() < Duration::from_secs(11));
+}
+
+#[test]
+fn game_round_trips_through_bincode() {
+    let game: Game<StandardScorer> = NOTATION.parse().unwrap();
+    let bytes = bincode::serialize(&game).unwrap();
+    let read: Game<StandardScorer> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(read.to_string(), game.to_string());
+    assert_eq!(read.discards_allowed, 1);
+}
+
+#[test]
+fn moves_and_errors_round_trip() {
+    let hands = vec![MoveType::StraightFlush, MoveType::Pair, MoveType::Trash];
+    let read: Vec<MoveType> = serde_json::from_str(&serde_json::to_string(&hands).unwrap()).unwrap();
+    assert_eq!(read, hands);
+    let errors = vec![MoveError::InvalidMove, MoveError::OutOfTime];
+    let bytes = bincode::serialize(&errors).unwrap();
+    assert_eq!(bincode::deserialize::<Vec<MoveError>>(&bytes).unwrap(), errors);
+}
+
+#[test]
+fn score_round_trips() {
+    let score = Score { value: 10, bonus: 150, multiplier: 2 };
+    let read: Score = serde_json::from_str(&serde_json::to_string(&score).unwrap()).unwrap();
+    assert_eq!(read.total(), 170);
+}
@@ -0,0 +1,91 @@
+extern crate cards;
+extern crate libthyme;
+
+use libthyme::game::*;
+use libthyme::score::*;
+use libthyme::strategy::*;
+use cards::card::{Card, Suit, Value};
+
+mod common;
+
+use common::game_with_cards;
+
+#[test]
+fn greedy_plays_highest_scoring_hand() {
+    let game = game_with_cards::<StandardScorer>(vec![
+        Card { value: Value::Five, suit: Suit::Clubs },
+        Card { value: Value::Five, suit: Suit::Diamonds },
+        Card { value: Value::Five, suit: Suit::Hearts }], 0);
+    let (hand, positions) = GreedyStrategy.choose(&game).unwrap();
+    assert_eq!(hand, MoveType::ThreeOfAKind);
+    assert_eq!(positions.len(), 3);
+}
+
+#[test]
+fn greedy_prefers_hand_to_discard() {
+    let game = game_with_cards::<StandardScorer>(vec![
+        Card { value: Value::Five, suit: Suit::Clubs },
+        Card { value: Value::Five, suit: Suit::Diamonds }], 2);
+    let (hand, _) = GreedyStrategy.choose(&game).unwrap();
+    assert_eq!(hand, MoveType::Pair);
+}
+
+#[test]
+fn strategies_discard_when_no_hand_remains() {
+    let game = game_with_cards::<StandardScorer>(vec![Card { value: Value::Five, suit: Suit::Clubs }], 1);
+    assert_eq!(GreedyStrategy.choose(&game).unwrap().0, MoveType::Trash);
+    assert_eq!(ClearStacksStrategy.choose(&game).unwrap().0, MoveType::Trash);
+    assert_eq!(ConserveDiscardsStrategy.choose(&game).unwrap().0, MoveType::Trash);
+    assert_eq!(LookaheadStrategy::new(2).choose(&game).unwrap().0, MoveType::Trash);
+    assert_eq!(RandomStrategy.choose(&game).unwrap().0, MoveType::Trash);
+}
+
+#[test]
+fn random_prefers_hand_to_discard() {
+    let game: Game<StandardScorer> = "TL: 6H; MC: 6C; BR: 2S; lucky: KH".parse().unwrap();
+    for _ in 0..10 {
+        assert_eq!(RandomStrategy.choose(&game).unwrap().0, MoveType::Pair);
+    }
+}
+
+#[test]
+fn creates_strategies_by_name() {
+    let game: Game<StandardScorer> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+    for name in STRATEGIES.iter() {
+        let strategy = strategy_named::<StandardScorer>(name).unwrap();
+        assert_eq!(strategy.choose(&game).unwrap().0, MoveType::Pair);
+    }
+    assert!(strategy_named::<StandardScorer>("cheat").is_none());
+}
+
+#[test]
+fn strategies_choose_nothing_when_no_moves_remain() {
+    let game = game_with_cards::<StandardScorer>(vec![Card { value: Value::Five, suit: Suit::Clubs }], 0);
+    assert!(GreedyStrategy.choose(&game).is_none());
+    assert!(ClearStacksStrategy.choose(&game).is_none());
+    assert!(ConserveDiscardsStrategy.choose(&game).is_none());
+    assert!(LookaheadStrategy::new(2).choose(&game).is_none());
+    assert!(RandomStrategy.choose(&game).is_none());
+}
+
+#[test]
+fn clear_stacks_plays_from_shortest_stacks() {
+    let mut game = game_with_cards::<StandardScorer>(vec![
+        Card { value: Value::Five, suit: Suit::Clubs },
+        Card { value: Value::Five, suit: Suit::Diamonds },
+        Card { value: Value::Five, suit: Suit::Hearts }], 0);
+    game.board.stacks[0].cards.insert(0, Card { value: Value::Two, suit: Suit::Hearts });
+    let (hand, positions) = ClearStacksStrategy.choose(&game).unwrap();
+    assert_eq!(hand, MoveType::Pair);
+    assert!(!positions.contains(&game.board.stacks[0].position));
+}
+
+#[test]
+fn lookahead_avoids_dead_end() {
+    // The straight scores more now but leaves no moves, while the pair
+    // uncovers a second pair
+    let game: Game<StandardScorer> = "TL: 9S 5C; TC: KS 4C; MC: 9D 5D; BR: 2C 6S; lucky: KH; discards: 0/0"
+        .parse().unwrap();
+    assert_eq!(GreedyStrategy.choose(&game).unwrap().0, MoveType::ThreeCardStraight);
+    assert_eq!(LookaheadStrategy::new(2).choose(&game).unwrap().0, MoveType::Pair);
+}
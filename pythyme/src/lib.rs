@@ -0,0 +1,336 @@
+//! Python bindings for libthyme, built as the `pythyme` module.
+//!
+//! Positions are written as labels such as `TL` or `BC`, and cards as codes
+//! such as `10H`, as in the notation used by libthyme.
+
+use cards::card::Card;
+use libthyme::board::{Board,Position};
+use libthyme::card::classify;
+use libthyme::card_code::{CardStyle,format,parse};
+use libthyme::error::ThymeError;
+use libthyme::game::{Game,GameStatus,MoveType};
+use libthyme::notation::{format_position,parse_position};
+use libthyme::score::{Play,Scorer,StandardScorer};
+use libthyme::strategy::{STRATEGIES,Strategy};
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::time::Duration;
+
+create_exception!(pythyme, MoveError, PyValueError, "A move which cannot be played");
+
+/// A hand which can be played from the board
+#[pyclass(name = "MoveType", eq, eq_int)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum PyMoveType {
+    StraightFlush,
+    FourOfAKind,
+    Flush,
+    FullHouse,
+    FiveCardStraight,
+    ThreeOfAKind,
+    ThreeCardStraight,
+    Pair,
+    Trash,
+}
+
+/// A layout of stacks dealt from a deck and the lucky card
+#[pyclass(name = "Board")]
+#[derive(Clone)]
+pub struct PyBoard {
+    board: Board,
+}
+
+/// A game played on a board, scored with the standard scorer
+#[pyclass(name = "Game")]
+#[derive(Clone)]
+pub struct PyGame {
+    game: Game<StandardScorer>,
+}
+
+/// A hand played from the board
+#[pyclass(name = "Play", get_all)]
+#[derive(Clone)]
+pub struct PyPlay {
+    hand: PyMoveType,
+    cards: Vec<String>,
+    cleared_positions: Vec<String>,
+    points: i32,
+}
+
+#[pymethods]
+impl PyBoard {
+
+    /// Deal a board from a shuffled deck, or from a seed so the same board
+    /// can be dealt again
+    #[new]
+    #[pyo3(signature = (seed=None))]
+    fn new(seed: Option<u64>) -> PyBoard {
+        let board = match seed {
+            Some(seed) => Board::with_seed(seed),
+            None => Board::new(),
+        };
+        return PyBoard { board: board }
+    }
+
+    /// Read a board written in notation
+    #[staticmethod]
+    fn from_notation(notation: &str) -> PyResult<PyBoard> {
+        let board = notation.parse().map_err(|e: ThymeError| {
+            PyValueError::new_err(e.to_string())
+        })?;
+        return Ok(PyBoard { board: board })
+    }
+
+    /// Labels of the positions on the board
+    fn positions(&self) -> Vec<String> {
+        return self.board.positions().into_iter().map(format_position).collect()
+    }
+
+    /// Code of the card on top of the stack at a position, or None if the
+    /// stack is empty
+    fn top(&self, position: &str) -> PyResult<Option<String>> {
+        let position = read_position(position)?;
+        return Ok(self.board.top(position).map(write_card))
+    }
+
+    /// Number of cards left in the stack at a position
+    fn count_cards(&self, position: &str) -> PyResult<usize> {
+        return Ok(self.board.count_cards(read_position(position)?))
+    }
+
+    /// Number of cards left on the board
+    fn count_all_cards(&self) -> usize {
+        return self.board.count_all_cards()
+    }
+
+    #[getter]
+    fn lucky_card(&self) -> String {
+        return write_card(self.board.lucky_card)
+    }
+
+    fn __str__(&self) -> String {
+        return self.board.to_string()
+    }
+}
+
+#[pymethods]
+impl PyGame {
+
+    /// Start a game on a board, or on a board dealt from a seed if none is
+    /// given, optionally with a time limit of more than zero seconds
+    #[new]
+    #[pyo3(signature = (board=None, seed=None, time_limit=None))]
+    fn new(board: Option<PyBoard>, seed: Option<u64>, time_limit: Option<f64>) -> PyResult<PyGame> {
+        let time_limit = match time_limit {
+            Some(seconds) => Some(Duration::try_from_secs_f64(seconds).ok()
+                .filter(|limit| !limit.is_zero())
+                .ok_or_else(|| PyValueError::new_err(format!("{} is not a time limit in seconds", seconds)))?),
+            None => None,
+        };
+        let board = board.unwrap_or_else(|| PyBoard::new(seed)).board;
+        let mut game = Game::with_board(board);
+        game.time_limit = time_limit;
+        return Ok(PyGame { game: game })
+    }
+
+    /// Read a game written in notation
+    #[staticmethod]
+    fn from_notation(notation: &str) -> PyResult<PyGame> {
+        let game = notation.parse().map_err(|e: ThymeError| {
+            PyValueError::new_err(e.to_string())
+        })?;
+        return Ok(PyGame { game: game })
+    }
+
+    /// A copy of the board as it is now
+    #[getter]
+    fn board(&self) -> PyBoard {
+        return PyBoard { board: self.game.board.clone() }
+    }
+
+    #[getter]
+    fn score(&self) -> i32 {
+        return self.game.score()
+    }
+
+    /// `InProgress`, `Won` or `Lost`
+    #[getter]
+    fn status(&self) -> &str {
+        return match self.game.status {
+            GameStatus::InProgress => "InProgress",
+            GameStatus::Won => "Won",
+            GameStatus::Lost { .. } => "Lost",
+        }
+    }
+
+    #[getter]
+    fn discards_allowed(&self) -> i32 {
+        return self.game.discards_allowed
+    }
+
+    /// Every hand played, in order
+    #[getter]
+    fn plays(&self) -> Vec<PyPlay> {
+        return self.game.plays.iter().map(|play| self.write_play(play)).collect()
+    }
+
+    fn is_finished(&self) -> bool {
+        return self.game.is_finished()
+    }
+
+    fn moves_remaining(&self) -> bool {
+        return self.game.moves_remaining()
+    }
+
+    /// Highest ranked hand the cards at a set of positions make
+    fn check(&self, positions: Vec<String>) -> PyResult<PyMoveType> {
+        let positions = read_positions(&positions)?;
+        return self.game.check(&positions).map(PyMoveType::from).map_err(move_error)
+    }
+
+    /// Every hand the cards at a set of positions make
+    fn check_all(&self, positions: Vec<String>) -> PyResult<Vec<PyMoveType>> {
+        let positions = read_positions(&positions)?;
+        let hands = self.game.check_all(&positions).map_err(move_error)?;
+        return Ok(hands.into_iter().map(PyMoveType::from).collect())
+    }
+
+    /// Points a hand would score if played from a set of positions
+    fn check_score(&self, hand: PyMoveType, positions: Vec<String>) -> PyResult<i32> {
+        let positions = read_positions(&positions)?;
+        let score = self.game.check_score(hand.into(), &positions).map_err(move_error)?;
+        return Ok(score.total())
+    }
+
+    /// Play a hand from a set of positions, adding it to the score
+    fn play(&mut self, hand: PyMoveType, positions: Vec<String>) -> PyResult<PyPlay> {
+        let positions = read_positions(&positions)?;
+        let play = self.game.play(hand.into(), &positions).map_err(move_error)?;
+        return Ok(self.write_play(&play))
+    }
+
+    /// Every move which can be played, as each hand the cards at a set of
+    /// positions can make
+    fn available_moves(&self) -> Vec<(PyMoveType, Vec<String>)> {
+        return self.game.available_moves().into_iter()
+            .map(|(hand, positions)| (hand.into(), write_positions(&positions)))
+            .collect()
+    }
+
+    /// Move suggested by a strategy, one of `STRATEGIES`, or None if no moves
+    /// remain
+    #[pyo3(signature = (strategy="solver"))]
+    fn hint(&self, strategy: &str) -> PyResult<Option<(PyMoveType, Vec<String>)>> {
+        let strategy = strategy_named(strategy)?;
+        return Ok(strategy.choose(&self.game)
+            .map(|(hand, positions)| (hand.into(), write_positions(&positions))))
+    }
+
+    fn __str__(&self) -> String {
+        return self.game.to_string()
+    }
+}
+
+impl PyGame {
+
+    fn write_play(&self, play: &Play) -> PyPlay {
+        return PyPlay {
+            hand: play.hand.into(),
+            cards: play.cards.iter().map(|card| write_card(*card)).collect(),
+            cleared_positions: write_positions(&play.cleared_positions),
+            points: self.game.scorer.check_play(play.clone()).total(),
+        }
+    }
+}
+
+/// Every hand a set of cards make, from the highest ranked, regardless of
+/// where they are on the board
+#[pyfunction(name = "classify")]
+fn classify_cards(cards: Vec<String>) -> PyResult<Vec<PyMoveType>> {
+    let cards = cards.iter()
+        .map(|code| parse(code).ok_or_else(|| PyValueError::new_err(format!("'{}' is not a card", code))))
+        .collect::<PyResult<Vec<Card>>>()?;
+    return Ok(classify(&cards).into_iter().map(PyMoveType::from).collect())
+}
+
+/// The `pythyme` Python module
+#[pymodule]
+pub fn pythyme(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyMoveType>()?;
+    module.add_class::<PyBoard>()?;
+    module.add_class::<PyGame>()?;
+    module.add_class::<PyPlay>()?;
+    module.add_function(wrap_pyfunction!(classify_cards, module)?)?;
+    module.add("MoveError", module.py().get_type::<MoveError>())?;
+    module.add("STRATEGIES", STRATEGIES.to_vec())?;
+    return Ok(())
+}
+
+fn strategy_named(name: &str) -> PyResult<Box<dyn Strategy<StandardScorer>>> {
+    return libthyme::strategy::strategy_named(name)
+        .ok_or_else(|| PyValueError::new_err(format!("'{}' is not a strategy", name)))
+}
+
+fn read_position(label: &str) -> PyResult<Position> {
+    return parse_position(label)
+        .ok_or_else(|| PyValueError::new_err(format!("'{}' is not a stack position", label)))
+}
+
+/// Read the labels of a selection of stacks, each of which may only be
+/// selected once
+fn read_positions(labels: &Vec<String>) -> PyResult<Vec<Position>> {
+    let mut positions = Vec::new();
+    for label in labels {
+        let position = read_position(label)?;
+        if positions.contains(&position) {
+            return Err(PyValueError::new_err(format!("'{}' is selected more than once", label)))
+        }
+        positions.push(position);
+    }
+    return Ok(positions)
+}
+
+fn write_positions(positions: &Vec<Position>) -> Vec<String> {
+    return positions.iter().map(|p| format_position(*p)).collect()
+}
+
+fn write_card(card: Card) -> String {
+    return format(card, CardStyle::Ascii)
+}
+
+fn move_error(error: libthyme::game::MoveError) -> PyErr {
+    return MoveError::new_err(error.to_string())
+}
+
+impl From<MoveType> for PyMoveType {
+    fn from(hand: MoveType) -> PyMoveType {
+        return match hand {
+            MoveType::StraightFlush => PyMoveType::StraightFlush,
+            MoveType::FourOfAKind => PyMoveType::FourOfAKind,
+            MoveType::Flush => PyMoveType::Flush,
+            MoveType::FullHouse => PyMoveType::FullHouse,
+            MoveType::FiveCardStraight => PyMoveType::FiveCardStraight,
+            MoveType::ThreeOfAKind => PyMoveType::ThreeOfAKind,
+            MoveType::ThreeCardStraight => PyMoveType::ThreeCardStraight,
+            MoveType::Pair => PyMoveType::Pair,
+            MoveType::Trash => PyMoveType::Trash,
+        }
+    }
+}
+
+impl From<PyMoveType> for MoveType {
+    fn from(hand: PyMoveType) -> MoveType {
+        return match hand {
+            PyMoveType::StraightFlush => MoveType::StraightFlush,
+            PyMoveType::FourOfAKind => MoveType::FourOfAKind,
+            PyMoveType::Flush => MoveType::Flush,
+            PyMoveType::FullHouse => MoveType::FullHouse,
+            PyMoveType::FiveCardStraight => MoveType::FiveCardStraight,
+            PyMoveType::ThreeOfAKind => MoveType::ThreeOfAKind,
+            PyMoveType::ThreeCardStraight => MoveType::ThreeCardStraight,
+            PyMoveType::Pair => MoveType::Pair,
+            PyMoveType::Trash => MoveType::Trash,
+        }
+    }
+}
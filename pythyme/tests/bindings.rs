@@ -0,0 +1,124 @@
+use pyo3::ffi::c_str;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::ffi::CStr;
+
+/// Run Python code with the module imported as `thyme`, failing if it raises
+fn run(code: &CStr) {
+    Python::with_gil(|py| {
+        let globals = PyDict::new(py);
+        globals.set_item("thyme", pyo3::wrap_pymodule!(pythyme::pythyme)(py)).unwrap();
+        if let Err(error) = py.run(code, Some(&globals), None) {
+            error.display(py);
+            panic!("{}", error);
+        }
+    });
+}
+
+#[test]
+fn deals_board_from_seed() {
+    run(c_str!(r#"
+board = thyme.Board(seed=7)
+assert board.count_all_cards() == 51
+assert board.positions()[0] == "TL"
+assert board.count_cards("TL") == 8
+assert str(board) == str(thyme.Board(seed=7))
+assert thyme.Board.from_notation(str(board)).lucky_card == board.lucky_card
+"#));
+}
+
+#[test]
+fn classifies_cards() {
+    run(c_str!(r#"
+assert thyme.classify(["5H", "5D"]) == [thyme.MoveType.Pair]
+assert thyme.classify(["3H", "4H", "5H"]) == [thyme.MoveType.ThreeCardStraight]
+assert thyme.classify(["2H", "9D"]) == []
+"#));
+}
+
+#[test]
+fn checks_and_plays_hands() {
+    run(c_str!(r#"
+game = thyme.Game(seed=7)
+assert game.check(["TL"]) == thyme.MoveType.Trash
+hand, positions = [m for m in game.available_moves() if m[0] != thyme.MoveType.Trash][0]
+points = game.check_score(hand, positions)
+play = game.play(hand, positions)
+assert play.hand == hand
+assert len(play.cards) == len(positions)
+assert play.points == points
+assert game.score == points
+assert len(game.plays) == 1
+assert game.board.count_all_cards() == 51 - len(positions)
+"#));
+}
+
+#[test]
+fn raises_move_errors() {
+    run(c_str!(r#"
+game = thyme.Game(seed=7)
+try:
+    game.check(["TL", "TR"])
+    assert False
+except thyme.MoveError as error:
+    assert str(error) == "A hand must use cards from more than one row"
+try:
+    game.play(thyme.MoveType.Flush, ["TL"])
+    assert False
+except thyme.MoveError:
+    pass
+try:
+    game.check(["XX"])
+    assert False
+except ValueError as error:
+    assert "XX" in str(error)
+"#));
+}
+
+#[test]
+fn rejects_repeated_positions() {
+    run(c_str!(r#"
+game = thyme.Game.from_notation("TL: 5H; ML: 5D; lucky: KH")
+for attempt in (lambda: game.check(["TL", "ML", "TL"]),
+                lambda: game.check_score(thyme.MoveType.Pair, ["TL", "ML", "TL"]),
+                lambda: game.play(thyme.MoveType.Pair, ["TL", "ML", "TL"])):
+    try:
+        attempt()
+        assert False
+    except ValueError as error:
+        assert "TL" in str(error)
+assert game.score == 0
+assert len(game.plays) == 0
+"#));
+}
+
+#[test]
+fn rejects_invalid_time_limits() {
+    run(c_str!(r#"
+assert thyme.Game(seed=7, time_limit=90.5).status == "InProgress"
+for time_limit in (0, -1.0, float("nan"), float("inf")):
+    try:
+        thyme.Game(seed=7, time_limit=time_limit)
+        assert False
+    except ValueError as error:
+        assert "time limit" in str(error)
+"#));
+}
+
+#[test]
+fn plays_game_with_hints() {
+    run(c_str!(r#"
+game = thyme.Game(seed=7)
+while not game.is_finished():
+    hand, positions = game.hint("greedy")
+    game.play(hand, positions)
+assert game.status in ("Won", "Lost")
+assert game.hint() is None
+assert "random" in thyme.STRATEGIES
+try:
+    game.hint("nonsense")
+    assert False
+except ValueError:
+    pass
+"#));
+}
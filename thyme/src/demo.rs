@@ -0,0 +1,220 @@
+use libthyme::board::Position;
+use libthyme::game::{Game,MoveError,MoveType};
+use libthyme::score::{Scorer,StandardScorer};
+use libthyme::strategy::{STRATEGIES,Strategy,strategy_named};
+use ui::{Action,UI};
+use ui::animation::FRAME_DURATION;
+use ui::renderer::{initialize_screen,get_action,redraw,cleanup,set_input_timeout};
+
+use {hand_message,play_message,status_message};
+
+const USAGE: &str = "Usage: thyme --demo [--delay MILLISECONDS] [--strategy NAME] [--no-animation]";
+
+/// Steps taken by the bot, one per tick of the demo
+enum Step {
+    /// Choose the next move and select its cards
+    Choose,
+    /// Play the selected cards
+    Play(MoveType, Vec<Position>),
+    /// Show the result of the game before dealing a new one
+    Finished,
+}
+
+struct Options {
+    /// Milliseconds between each step of the bot
+    delay: i32,
+    strategy: &'static str,
+    animate: bool,
+}
+
+/// Run a game played by a bot until quit, pausing between each step so the
+/// selection of each move is shown before it is played. The demo stops if the
+/// bot chooses a move which cannot be played.
+/// Arguments are those following the `--demo` flag.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let strategy = strategy_named(options.strategy).ok_or(USAGE.to_string())?;
+    let ui = &mut UI::new();
+    ui.animations_enabled = options.animate;
+    let game = &mut Game::<StandardScorer>::new();
+    let mut step = Step::Choose;
+    let mut paused = false;
+    let mut failure = None;
+    let mut screen = initialize_screen();
+    let screen = screen.as_mut();
+    redraw(screen, ui, game, true);
+    loop {
+        let mut refresh = false;
+        set_input_timeout(screen, if ui.is_animating() { FRAME_DURATION } else { options.delay });
+        match get_action(screen) {
+            Action::Quit | Action::Disconnect => { break },
+            Action::ToggleSelection => {
+                paused = !paused;
+                ui.message = if paused {
+                    "Paused - press space to resume".to_string()
+                } else {
+                    "".to_string()
+                };
+            },
+            Action::Resize => refresh = true,
+            Action::Idle if !paused && !ui.is_animating() => {
+                match take_step(step, strategy.as_ref(), game, ui) {
+                    Ok(next) => step = next,
+                    Err(error) => {
+                        failure = Some(format!("The {} strategy chose a move which cannot be played: {}",
+                                               options.strategy, error));
+                        break
+                    },
+                }
+            },
+            _ => {}
+        }
+        if paused {
+            ui.hold_animations();
+        } else {
+            ui.advance_animations();
+        }
+        redraw(screen, ui, game, refresh);
+    }
+    cleanup(screen);
+    return match failure {
+        Some(message) => Err(message),
+        None => Ok(()),
+    }
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options { delay: 600, strategy: STRATEGIES[0], animate: true };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--no-animation" {
+            options.animate = false;
+            continue;
+        }
+        let value = iter.next().ok_or(USAGE.to_string())?;
+        match arg.as_str() {
+            "--delay" => options.delay = value.parse().map_err(|_| USAGE.to_string())?,
+            "--strategy" => options.strategy = STRATEGIES.iter()
+                .find(|name| **name == value.as_str())
+                .ok_or(USAGE.to_string())?,
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    return Ok(options)
+}
+
+/// Perform a step of the bot's turn, returning the step to take next, or
+/// why the chosen move could not be played
+fn take_step<T: Scorer>(step: Step, strategy: &dyn Strategy<T>, game: &mut Game<T>,
+                        ui: &mut UI) -> Result<Step, MoveError> {
+    match step {
+        Step::Choose => {
+            let choice = strategy.choose(game);
+            if choice.is_none() {
+                game.update_status();
+                ui.message = status_message(game);
+                return Ok(Step::Finished)
+            }
+            let (hand, positions) = choice.unwrap();
+            ui.cursor_position = positions[0];
+            ui.selection = positions.clone();
+            ui.message = format!("Playing '{}'", hand_message(hand));
+            return Ok(Step::Play(hand, positions))
+        },
+        Step::Play(hand, positions) => {
+            let play = game.play(hand, &positions)?;
+            ui.message = play_message(&play, &game.scorer);
+            ui.animate_play(&game.board, &play);
+            ui.selection.clear();
+            return Ok(Step::Choose)
+        },
+        Step::Finished => {
+            let animate = ui.animations_enabled;
+            *game = Game::new();
+            *ui = UI::new();
+            ui.animations_enabled = animate;
+            return Ok(Step::Choose)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libthyme::strategy::GreedyStrategy;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        return values.iter().map(|v| v.to_string()).collect()
+    }
+
+    /// Suggests playing every stack as a straight flush, whatever the cards are
+    struct IllegalStrategy;
+
+    impl<T: Scorer> Strategy<T> for IllegalStrategy {
+        fn choose(&self, game: &Game<T>) -> Option<(MoveType, Vec<Position>)> {
+            return Some((MoveType::StraightFlush, game.board.positions()))
+        }
+    }
+
+    #[test]
+    fn parses_default_options() {
+        let options = parse_options(&[]).unwrap();
+        assert_eq!(options.delay, 600);
+        assert_eq!(options.strategy, "greedy");
+        assert!(options.animate);
+    }
+
+    #[test]
+    fn parses_options() {
+        let options = parse_options(&args(&["--delay", "50", "--no-animation", "--strategy", "solver"])).unwrap();
+        assert_eq!(options.delay, 50);
+        assert_eq!(options.strategy, "solver");
+        assert!(!options.animate);
+    }
+
+    #[test]
+    fn rejects_invalid_options() {
+        assert!(parse_options(&args(&["--delay"])).is_err());
+        assert!(parse_options(&args(&["--delay", "soon"])).is_err());
+        assert!(parse_options(&args(&["--strategy", "cheat"])).is_err());
+        assert!(parse_options(&args(&["--games", "5"])).is_err());
+    }
+
+    #[test]
+    fn selects_cards_then_plays_them() {
+        let mut game: Game<StandardScorer> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+        let ui = &mut UI::new();
+        let step = take_step(Step::Choose, &GreedyStrategy, &mut game, ui).unwrap();
+        assert!(matches!(step, Step::Play(MoveType::Pair, _)));
+        assert_eq!(ui.selection.len(), 2);
+        assert_eq!(game.board.count_all_cards(), 2);
+        let step = take_step(step, &GreedyStrategy, &mut game, ui).unwrap();
+        assert!(matches!(step, Step::Choose));
+        assert!(ui.selection.is_empty());
+        assert_eq!(game.board.count_all_cards(), 0);
+        assert_eq!(game.plays.len(), 1);
+    }
+
+    #[test]
+    fn finishes_then_deals_new_game() {
+        let mut game: Game<StandardScorer> = "TL: 6H; lucky: KH; discards: 0/2".parse().unwrap();
+        let ui = &mut UI::new();
+        ui.animations_enabled = false;
+        let step = take_step(Step::Choose, &GreedyStrategy, &mut game, ui).unwrap();
+        assert!(matches!(step, Step::Finished));
+        assert!(game.is_finished());
+        let step = take_step(step, &GreedyStrategy, &mut game, ui).unwrap();
+        assert!(matches!(step, Step::Choose));
+        assert!(!game.is_finished());
+        assert!(!ui.animations_enabled);
+    }
+
+    #[test]
+    fn stops_on_illegal_move() {
+        let mut game: Game<StandardScorer> = "TL: 6H; MC: 6C; lucky: KH".parse().unwrap();
+        let ui = &mut UI::new();
+        let step = take_step(Step::Choose, &IllegalStrategy, &mut game, ui).unwrap();
+        assert!(take_step(step, &IllegalStrategy, &mut game, ui).is_err());
+        assert_eq!(game.board.count_all_cards(), 2);
+    }
+}
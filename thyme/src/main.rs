@@ -1,38 +1,257 @@
 extern crate libthyme;
 extern crate ui;
 
+mod demo;
+mod race;
+mod serve;
+mod simulate;
+mod telnet;
+
 use libthyme::game::*;
+use libthyme::card_code::{CardStyle,format_all};
+use libthyme::race::Race;
 use libthyme::score::{Play,Scorer,StandardScorer};
+use libthyme::strategy::{LookaheadStrategy,Strategy};
 use ui::{Action,UI};
-use ui::renderer::{initialize_screen,get_action,redraw,cleanup};
+use ui::animation::FRAME_DURATION;
+use ui::dialog::{Answer,Dialog};
+use ui::renderer::{initialize_screen,get_action,redraw,cleanup,set_input_timeout,history_page_size};
+use ui::screen::Screen;
+use std::env;
+use std::process;
+use std::time::Duration;
 
-/// Run loop of the thyme game, which interprets key presses and processes
-/// input by the user.
+const USAGE: &str = "Usage: thyme [--time-limit SECONDS] [--no-animation]";
+
+/// Milliseconds to wait for input before redrawing the clock
+pub const CLOCK_TICK: i32 = 250;
+
+/// Purpose of the open dialog
+#[derive(Clone, Copy)]
+enum Prompt {
+    /// Confirm quitting a game in progress
+    Quit,
+    /// Confirm dealing a new game over a game in progress
+    NewGame,
+    /// Hide the board and stop the clock until a key is pressed
+    Pause,
+    /// Show how the game ended and offer a new game
+    GameOver,
+    /// Show who won the race
+    RaceOver,
+}
+
+/// Run the subcommand named in the arguments, or play a game if none is given
 pub fn main() {
-    let mut ui = &mut UI::new();
-    let game = &mut Game::<StandardScorer>::new();
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(|a| a.as_str()) == Some("simulate") {
+        if let Err(message) = simulate::run(&args[1..]) {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+        return
+    } else if args.first().map(|a| a.as_str()) == Some("serve") {
+        if let Err(message) = serve::run(&args[1..]) {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+        return
+    } else if args.first().map(|a| a.as_str()) == Some("race") {
+        if let Err(message) = race::run(&args[1..]) {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+        return
+    } else if args.first().map(|a| a.as_str()) == Some("telnet") {
+        if let Err(message) = telnet::run(&args[1..]) {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+        return
+    } else if args.first().map(|a| a.as_str()) == Some("--demo") {
+        if let Err(message) = demo::run(&args[1..]) {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+        return
+    }
+    if let Err(message) = run_game(&args) {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
+
+/// Play a game in the terminal. Arguments are the options of the game.
+fn run_game(args: &[String]) -> Result<(), String> {
+    let mut animate = true;
+    let mut time_limit = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--no-animation" => animate = false,
+            "--time-limit" => {
+                time_limit = Some(iter.next().and_then(|value| parse_time_limit(value)).ok_or(USAGE.to_string())?);
+            },
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let game = match time_limit {
+        Some(limit) => Game::timed(limit),
+        None => Game::new(),
+    };
+    let mut screen = initialize_screen();
+    play_game(screen.as_mut(), game, animate, None);
+    cleanup(screen.as_mut());
+    return Ok(())
+}
+
+/// Run loop of the thyme game, which interprets key presses and processes
+/// input by the user until the player quits or disconnects. Input is waited
+/// on for a short time so the clock and animations can be redrawn while no
+/// keys are pressed.
+/// In a race, the progress of the game is shared with the other players and
+/// no new game can be dealt.
+fn play_game(screen: &mut dyn Screen, mut game: Game<StandardScorer>, animate: bool, race: Option<&race::Entry>) {
+    let ui = &mut UI::new();
+    ui.animations_enabled = animate;
+    let game = &mut game;
     let mut hand = None;
-    initialize_screen();
-    redraw(ui, game, true);
+    let mut prompt = None;
+    let mut race_finished = false;
+    redraw(screen, ui, game, true);
     loop {
         let mut refresh = false;
-        let action = get_action();
-        match action {
-            Action::CursorUp
-            | Action::CursorDown
-            | Action::CursorRight
-            | Action::CursorLeft =>    ui.move_cursor(action),
-            Action::Play =>            play_hand(hand, game, ui),
-            Action::ToggleSelection => hand = update_selection(game, ui),
-            Action::Quit => { break },
-            Action::Help => {},
-            Action::Hint => {},
-            Action::Resize => refresh = true,
-            _ => ui.message = "Press 'Q' to quit".to_string()
+        let status = game.status;
+        set_input_timeout(screen, if ui.is_animating() { FRAME_DURATION } else { CLOCK_TICK });
+        let action = get_action(screen);
+        if let Action::Disconnect = action {
+            break
+        } else if let Some(current) = prompt {
+            let answer = ui.dialog.as_ref().and_then(|d| d.answer(&action));
+            if let Some(answer) = answer {
+                close_prompt(game, ui);
+                prompt = None;
+                match (current, answer) {
+                    (Prompt::Quit, Answer::Yes) => { break },
+                    (Prompt::NewGame, Answer::Yes)
+                    | (Prompt::GameOver, Answer::Yes) => hand = new_game(game, ui),
+                    _ => {}
+                }
+            }
+            refresh = matches!(action, Action::Resize);
+        } else if ui.show_history {
+            match action {
+                Action::CursorUp => ui.history.scroll_up(history_page_size(screen)),
+                Action::CursorDown => ui.history.scroll_down(),
+                Action::ToggleHistory | Action::Cancel => ui.show_history = false,
+                Action::Resize => refresh = true,
+                _ => {}
+            }
+        } else {
+            match action {
+                Action::Play
+                | Action::ToggleSelection
+                | Action::Hint if race_finished => {
+                    ui.message = "The race is over - press 'Q' to quit".to_string()
+                },
+                Action::CursorUp
+                | Action::CursorDown
+                | Action::CursorRight
+                | Action::CursorLeft =>    ui.move_cursor(action),
+                Action::Play =>            play_hand(hand, game, ui),
+                Action::ToggleSelection => hand = update_selection(game, ui),
+                Action::Quit if game.is_finished() || race_finished => { break },
+                Action::Quit =>            prompt = open_prompt(Prompt::Quit, game, ui),
+                Action::NewGame if race.is_some() => {
+                    ui.message = "New games cannot be dealt during a race".to_string()
+                },
+                Action::NewGame if game.plays.is_empty() || game.is_finished() => {
+                    hand = new_game(game, ui)
+                },
+                Action::NewGame =>         prompt = open_prompt(Prompt::NewGame, game, ui),
+                Action::Pause =>           prompt = open_prompt(Prompt::Pause, game, ui),
+                Action::ToggleHistory =>   ui.show_history = true,
+                Action::Help => {},
+                Action::Hint =>            hand = show_hint(game, ui),
+                Action::Resize => refresh = true,
+                Action::Idle => {},
+                _ => ui.message = "Press 'Q' to quit".to_string()
+            }
         }
-        redraw(ui, game, refresh);
+        game.update_status();
+        if game.status != status && game.is_finished() {
+            hand = None;
+            ui.selection.clear();
+            ui.log(status_message(game));
+            if race.is_none() {
+                prompt = open_prompt(Prompt::GameOver, game, ui);
+            }
+        }
+        if let Some(entry) = race {
+            let standings = entry.update(game);
+            let finished = standings.is_finished();
+            ui.race = Some((standings, entry.index()));
+            if finished && !race_finished {
+                race_finished = true;
+                hand = None;
+                ui.selection.clear();
+                prompt = open_prompt(Prompt::RaceOver, game, ui);
+            }
+        }
+        ui.advance_animations();
+        redraw(screen, ui, game, refresh);
     }
-    cleanup();
+}
+
+/// Read the seconds given with `--time-limit`, which must be at least one
+pub fn parse_time_limit(value: &str) -> Option<Duration> {
+    return value.parse().ok().filter(|&seconds| seconds > 0).map(Duration::from_secs)
+}
+
+/// Open the dialog for a prompt in place of any open dialog, stopping the
+/// clock while it hides the board
+fn open_prompt<T: Scorer>(prompt: Prompt, game: &mut Game<T>, ui: &mut UI) -> Option<Prompt> {
+    close_prompt(game, ui);
+    ui.dialog = Some(match prompt {
+        Prompt::Quit => Dialog::confirm("Quit?", "The current game will be lost").hiding_board(),
+        Prompt::NewGame => Dialog::confirm("New Game?", "The current game will be lost").hiding_board(),
+        Prompt::Pause => Dialog::message("Paused", "The board is hidden while paused").hiding_board(),
+        Prompt::GameOver => match game.status {
+            GameStatus::Lost { cards_left } => Dialog::confirm("Game Over", &format!(
+                    "{} cards left, final score: {} - play again?", cards_left, game.score())),
+            _ => Dialog::confirm(&success_message(), &format!(
+                    "Final score: {} - play again?", game.score())),
+        },
+        Prompt::RaceOver => match ui.race {
+            Some((ref race, player)) => {
+                let (title, message) = race_result(race, player);
+                Dialog::message(&title, &message)
+            },
+            None => Dialog::message("Race Over", ""),
+        },
+    });
+    if ui.dialog.as_ref().is_some_and(|d| d.hides_board) {
+        game.pause();
+    }
+    return Some(prompt)
+}
+
+/// Close the open dialog, restarting the clock if it was stopped
+fn close_prompt<T: Scorer>(game: &mut Game<T>, ui: &mut UI) {
+    if ui.dialog.take().is_some_and(|d| d.hides_board) {
+        game.resume();
+    }
+}
+
+/// Deal a new game with the same time limit, keeping the animation setting
+fn new_game<T: Scorer>(game: &mut Game<T>, ui: &mut UI) -> Option<MoveType> {
+    let time_limit = game.time_limit;
+    let animate = ui.animations_enabled;
+    *game = Game::new();
+    game.time_limit = time_limit;
+    *ui = UI::new();
+    ui.animations_enabled = animate;
+    return None
 }
 
 /// Play the cards in the selected positions if possible, and if so then clear
@@ -42,17 +261,12 @@ fn play_hand<T: Scorer>(hand: Option<MoveType>, game: &mut Game<T>, ui: &mut UI)
     if hand.is_some() {
         let result = game.play(hand.unwrap(), &ui.selection);
         if result.is_ok() {
-            game.scorer.add_play(result.ok().unwrap());
+            let play = result.ok().unwrap();
+            ui.log(play_message(&play, &game.scorer));
+            ui.animate_play(&game.board, &play);
             ui.selection.clear();
-            if game.moves_remaining() {
-                ui.message = play_message(hand.unwrap())
-            } else if game.board.positions_remaining().len() > 0 {
-                ui.message = error_message(MoveError::NoMovesRemain)
-            } else {
-                ui.message = success_message();
-            }
         } else {
-            ui.message = error_message(result.err().unwrap())
+            ui.log(result.err().unwrap().to_string())
         }
     }
 }
@@ -61,8 +275,8 @@ fn play_hand<T: Scorer>(hand: Option<MoveType>, game: &mut Game<T>, ui: &mut UI)
 /// position and the game has not ended.
 /// Prints a message reflecting the current game state.
 fn update_selection<T: Scorer>(game: &mut Game<T>, ui: &mut UI) -> Option<MoveType> {
-    if !game.moves_remaining() {
-        ui.message = error_message(MoveError::NoMovesRemain);
+    if game.is_finished() {
+        ui.message = status_message(game);
         return None;
     }
     if game.board.top(ui.cursor_position).is_some() {
@@ -78,44 +292,86 @@ fn update_selection<T: Scorer>(game: &mut Game<T>, ui: &mut UI) -> Option<MoveTy
         ui.message = check_message(hand, ui, game);
         return Some(hand)
     } else {
-        ui.message = error_message(check.err().unwrap());
+        ui.message = check.err().unwrap().to_string();
         return None;
     }
 }
 
+/// Select the cards of the move suggested by the hint strategy.
+/// Prints a message describing the suggested move.
+fn show_hint<T: Scorer + Clone>(game: &mut Game<T>, ui: &mut UI) -> Option<MoveType> {
+    let choice = LookaheadStrategy::new(2).choose(game);
+    if choice.is_none() {
+        ui.message = MoveError::NoMovesRemain.to_string();
+        return None;
+    }
+    let (hand, positions) = choice.unwrap();
+    ui.selection = positions;
+    ui.message = format!("Hint: {}", check_message(hand, ui, game));
+    return Some(hand)
+}
+
+/// Title and message announcing the winner of a finished race
+fn race_result(race: &Race, player: usize) -> (String, String) {
+    let winner = match race.winner() {
+        Some(winner) => winner,
+        None => return ("Race Over".to_string(), "Every other player left the race".to_string()),
+    };
+    let racer = &race.racers[winner];
+    let title = if winner == player {
+        "You won the race!".to_string()
+    } else {
+        format!("{} won the race", racer.name)
+    };
+    let message = if racer.status == GameStatus::Won {
+        format!("{} cleared the board first with {} points", racer.name, racer.score)
+    } else {
+        format!("{} finished with the highest score, {} points", racer.name, racer.score)
+    };
+    return (title, message)
+}
+
 fn success_message() -> String {
     return "You WON!".to_string();
 }
 
-fn error_message(code: MoveError) -> String {
-    return match code {
-        MoveError::InvalidMove => "This selection is not a hand",
-        MoveError::InvalidHand => "This selection does not match the hand",
-        MoveError::NeedMultipleRows => "A hand must be played from multiple rows",
-        MoveError::NoMovesRemain => "Game Over - No moves left",
-        MoveError::NoDiscardsRemain => "No discards remain",
-        MoveError::TwoPairIsInvalid => "Two pair is not a hand",
-    }.to_string()
+/// Describe how a finished game ended
+fn status_message<T: Scorer>(game: &Game<T>) -> String {
+    return match game.status {
+        GameStatus::InProgress => "".to_string(),
+        GameStatus::Won => format!("{} Final score: {}", success_message(), game.score()),
+        GameStatus::Lost { cards_left } => {
+            let reason = if game.is_out_of_time() { MoveError::OutOfTime } else { MoveError::NoMovesRemain };
+            format!("{} - {} cards left, final score: {}", reason, cards_left, game.score())
+        },
+    }
 }
 
 fn check_message<T: Scorer>(hand: MoveType, ui: &UI, game: &mut Game<T>) -> String {
-    if hand == MoveType::Trash {
-        return format!("Press return to discard this card.");
-    }
-    let cleared = ui.selection.iter().filter(|p| game.board.count_cards(**p) == 1).map(|p| *p).collect();
-    let score = game.scorer.check_play(Play {
-        cards: game.board.peek(&ui.selection).unwrap(),
-        cleared_positions: cleared,
-        hand: hand
-    });
-    return format!("Press return to play '{}' (+{} x{})",
+    let score = game.check_score(hand, &ui.selection).ok().unwrap();
+    let total = game.score() + score.total();
+    if hand == MoveType::Trash && score.bonus == 0 {
+        return "Press return to discard this card.".to_string();
+    } else if hand == MoveType::Trash {
+        return format!("Press return to discard this card (+{} bonus, total {})",
+                       score.bonus,
+                       total)
+    }
+    let bonus = if score.bonus > 0 { format!(" +{} bonus", score.bonus) } else { "".to_string() };
+    return format!("Press return to play '{}' (+{} x{}{} = {}, total {})",
                    hand_message(hand),
                    score.value,
-                   score.multiplier)
+                   score.multiplier,
+                   bonus,
+                   score.total(),
+                   total)
 }
 
-fn play_message(code: MoveType) -> String {
-    return format!("Played '{}'", hand_message(code))
+fn play_message<T: Scorer>(play: &Play, scorer: &T) -> String {
+    return format!("Played '{}' ({}) for {} points",
+                   hand_message(play.hand),
+                   format_all(&play.cards, CardStyle::Unicode),
+                   scorer.check_play(play.clone()).total())
 }
 
 fn hand_message(code: MoveType) -> String {
@@ -131,3 +387,59 @@ fn hand_message(code: MoveType) -> String {
         MoveType::Trash => "Discard",
     }.to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libthyme::board::Board;
+
+    fn timed_game() -> Game<StandardScorer> {
+        let mut game = Game::with_board(Board::with_seed(7));
+        game.time_limit = Some(Duration::from_secs(60));
+        return game
+    }
+
+    #[test]
+    fn confirm_prompts_stop_clock_until_answered() {
+        for prompt in [Prompt::Quit, Prompt::NewGame, Prompt::Pause] {
+            let mut game = timed_game();
+            let ui = &mut UI::new();
+            open_prompt(prompt, &mut game, ui);
+            assert!(ui.dialog.as_ref().unwrap().hides_board);
+            assert!(game.paused.is_some());
+            close_prompt(&mut game, ui);
+            assert!(ui.dialog.is_none());
+            assert!(game.paused.is_none());
+        }
+    }
+
+    #[test]
+    fn time_limit_is_at_least_one_second() {
+        assert_eq!(parse_time_limit("90"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_time_limit("0"), None);
+        assert_eq!(parse_time_limit("-5"), None);
+        assert_eq!(parse_time_limit("soon"), None);
+    }
+
+    #[test]
+    fn race_over_prompt_replaces_open_prompt() {
+        let mut game = timed_game();
+        let ui = &mut UI::new();
+        open_prompt(Prompt::Pause, &mut game, ui);
+        open_prompt(Prompt::RaceOver, &mut game, ui);
+        assert!(!ui.dialog.as_ref().unwrap().hides_board);
+        assert!(game.paused.is_none());
+    }
+
+    #[test]
+    fn game_over_prompt_shows_board() {
+        let mut game = timed_game();
+        let ui = &mut UI::new();
+        game.status = GameStatus::Lost { cards_left: 4 };
+        open_prompt(Prompt::GameOver, &mut game, ui);
+        assert!(!ui.dialog.as_ref().unwrap().hides_board);
+        assert!(game.paused.is_none());
+        close_prompt(&mut game, ui);
+        assert!(ui.dialog.is_none());
+    }
+}
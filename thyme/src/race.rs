@@ -0,0 +1,241 @@
+extern crate rand;
+
+use libthyme::board::Board;
+use libthyme::game::Game;
+use libthyme::race::Race;
+use libthyme::score::StandardScorer;
+use ui::{Action,UI};
+use ui::dialog::Dialog;
+use ui::renderer::{cleanup,get_action,redraw,set_input_timeout};
+use ui::screen::Screen;
+use std::io::Write;
+use std::net::{TcpListener,TcpStream};
+use std::sync::{Arc,Mutex,MutexGuard};
+use std::thread;
+use std::time::Duration;
+
+use telnet::{DEFAULT_PORT,open_screen};
+use {CLOCK_TICK,parse_time_limit,play_game};
+
+const USAGE: &str = "Usage: thyme race [--players N] [--port PORT] [--seed SEED] [--time-limit SECONDS] [--no-animation]";
+
+/// Number of players in a race unless another is given
+const DEFAULT_PLAYERS: usize = 2;
+
+/// Options of every race hosted by the server
+#[derive(Clone, Copy)]
+struct Settings {
+    players: usize,
+    /// Seed every race is dealt from, or None to deal each race at random
+    seed: Option<u64>,
+    time_limit: Option<Duration>,
+    animate: bool,
+}
+
+/// Players connected to the server and the race they are playing
+struct Lobby {
+    settings: Settings,
+    /// Tickets of the players in the race or waiting for it, in the order
+    /// they connected, which is also their order in the race
+    tickets: Vec<u64>,
+    next_ticket: u64,
+    /// Seed the next race is dealt from
+    seed: u64,
+    /// Number of players still connected
+    connected: usize,
+    /// The race being played, once enough players have connected
+    race: Option<Race>,
+}
+
+/// A player's place in a race, shared with the other players' connections
+pub struct Entry {
+    lobby: Arc<Mutex<Lobby>>,
+    index: usize,
+}
+
+/// Host races over TCP between players who connect with a telnet client.
+/// Each race starts once enough players have connected, and every player
+/// races on a board dealt from the same seed.
+/// Arguments are those following the `race` subcommand.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let (settings, port) = parse_args(args)?;
+    let listener = TcpListener::bind(("0.0.0.0", port)).map_err(|e| e.to_string())?;
+    let address = listener.local_addr().map_err(|e| e.to_string())?;
+    println!("Listening on port {}", address.port());
+    let lobby = Arc::new(Mutex::new(Lobby {
+        settings: settings,
+        tickets: vec![],
+        next_ticket: 1,
+        seed: 0,
+        connected: 0,
+        race: None,
+    }));
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(_) => continue,
+        };
+        let lobby = lobby.clone();
+        thread::spawn(move || {
+            let peer = stream.peer_addr().map(|a| a.to_string()).unwrap_or_default();
+            println!("{} connected", peer);
+            if let Err(error) = host_racer(stream, lobby) {
+                println!("{} failed: {}", peer, error);
+            }
+            println!("{} disconnected", peer);
+        });
+    }
+    return Ok(())
+}
+
+fn parse_args(args: &[String]) -> Result<(Settings, u16), String> {
+    let mut settings = Settings {
+        players: DEFAULT_PLAYERS,
+        seed: None,
+        time_limit: None,
+        animate: true,
+    };
+    let mut port = DEFAULT_PORT;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--no-animation" {
+            settings.animate = false;
+            continue;
+        }
+        let value = iter.next().ok_or(USAGE.to_string())?;
+        match arg.as_str() {
+            "--players" => settings.players = value.parse().map_err(|_| USAGE.to_string())?,
+            "--port" => port = value.parse().map_err(|_| USAGE.to_string())?,
+            "--seed" => settings.seed = Some(value.parse().map_err(|_| USAGE.to_string())?),
+            "--time-limit" => settings.time_limit = Some(parse_time_limit(value).ok_or(USAGE.to_string())?),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    if settings.players < 2 {
+        return Err("A race needs at least 2 players".to_string())
+    }
+    return Ok((settings, port))
+}
+
+/// Join a player to the next race, then play it with them until they quit
+/// or disconnect
+fn host_racer(mut stream: TcpStream, lobby: Arc<Mutex<Lobby>>) -> Result<(), String> {
+    let ticket = match lock(&lobby).join() {
+        Some(ticket) => ticket,
+        None => {
+            let message = "A race is under way - try again once it is finished\r\n";
+            return stream.write_all(message.as_bytes()).map_err(|e| e.to_string())
+        },
+    };
+    let mut screen = match open_screen(stream) {
+        Ok(screen) => screen,
+        Err(error) => {
+            lock(&lobby).leave(ticket);
+            return Err(error)
+        },
+    };
+    if let Some(index) = wait_for_race(&mut screen, &lobby, ticket) {
+        let (board, settings) = {
+            let lobby = lock(&lobby);
+            (lobby.race.as_ref().map(Race::board), lobby.settings)
+        };
+        let mut game = Game::with_board(board.expect("Races are kept until every player leaves"));
+        game.time_limit = settings.time_limit;
+        let entry = Entry { lobby: lobby.clone(), index: index };
+        play_game(&mut screen, game, settings.animate, Some(&entry));
+    }
+    lock(&lobby).leave(ticket);
+    cleanup(&mut screen);
+    return Ok(())
+}
+
+/// Show how many players have joined until the race starts, returning the
+/// player's index in the race, or None if they left first
+fn wait_for_race(screen: &mut dyn Screen, lobby: &Mutex<Lobby>, ticket: u64) -> Option<usize> {
+    let ui = &mut UI::new();
+    ui.message = "Press 'Q' to leave".to_string();
+    // Only the title of the game is shown until the race starts, with the
+    // clock stopped
+    let game = &mut Game::<StandardScorer>::with_board(Board::with_seed(lock(lobby).seed));
+    game.pause();
+    set_input_timeout(screen, CLOCK_TICK);
+    let mut refresh = true;
+    loop {
+        {
+            let lobby = lock(lobby);
+            if lobby.race.is_some() {
+                return lobby.tickets.iter().position(|t| *t == ticket)
+            }
+            let message = format!("{} of {} players have joined", lobby.tickets.len(), lobby.settings.players);
+            ui.dialog = Some(Dialog::notice("Waiting for Players", &message).hiding_board());
+        }
+        redraw(screen, ui, game, refresh);
+        refresh = false;
+        match get_action(screen) {
+            Action::Quit | Action::Disconnect => return None,
+            Action::Resize => refresh = true,
+            _ => {}
+        }
+    }
+}
+
+impl Lobby {
+
+    /// Add a player to the next race, starting it if it is full, or return
+    /// None if a race is already under way
+    fn join(&mut self) -> Option<u64> {
+        if self.race.is_some() {
+            return None
+        }
+        if self.tickets.is_empty() {
+            self.seed = self.settings.seed.unwrap_or_else(rand::random);
+        }
+        let ticket = self.next_ticket;
+        self.next_ticket += 1;
+        self.tickets.push(ticket);
+        self.connected += 1;
+        if self.tickets.len() == self.settings.players {
+            self.race = Some(Race::new(self.seed, self.settings.players));
+        }
+        return Some(ticket)
+    }
+
+    /// Remove a player, forfeiting their game if the race is under way, and
+    /// make way for the next race once every player has left
+    fn leave(&mut self, ticket: u64) {
+        let index = match self.tickets.iter().position(|t| *t == ticket) {
+            Some(index) => index,
+            None => return,
+        };
+        self.connected -= 1;
+        match self.race {
+            Some(ref mut race) => race.leave(index),
+            None => { self.tickets.remove(index); },
+        }
+        if self.connected == 0 {
+            self.tickets.clear();
+            self.race = None;
+        }
+    }
+}
+
+impl Entry {
+
+    /// Position of the player in the race
+    pub fn index(&self) -> usize {
+        return self.index
+    }
+
+    /// Record the progress of the player's game, returning the standings of
+    /// every player
+    pub fn update(&self, game: &Game<StandardScorer>) -> Race {
+        let mut lobby = lock(&self.lobby);
+        let race = lobby.race.as_mut().expect("Races are kept until every player leaves");
+        race.update(self.index, game);
+        return race.clone()
+    }
+}
+
+fn lock(lobby: &Mutex<Lobby>) -> MutexGuard<'_, Lobby> {
+    return lobby.lock().unwrap_or_else(|e| e.into_inner())
+}
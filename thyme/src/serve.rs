@@ -0,0 +1,343 @@
+extern crate serde;
+extern crate serde_json;
+extern crate tiny_http;
+
+use libthyme::board::{Board,Position};
+use libthyme::card_code::{CardStyle,format};
+use libthyme::game::{Game,GameStatus,MoveError,MoveType};
+use libthyme::notation::{format_position,parse_position};
+use libthyme::score::{Play,Score,Scorer,StandardScorer};
+use self::serde::{Deserialize,Serialize};
+use self::tiny_http::{Header,Method,Request,Response,Server};
+use std::collections::HashMap;
+use std::sync::{Arc,Mutex};
+use std::thread;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: thyme serve [--port PORT]";
+
+/// Port the server listens on unless another is given
+const DEFAULT_PORT: u16 = 8080;
+
+/// Number of requests handled at once
+const WORKER_COUNT: usize = 4;
+
+/// A game being played through the server
+struct Session {
+    game: Game<StandardScorer>,
+    /// Seed the board was dealt from, if one was given
+    seed: Option<u64>,
+    /// The game as it was before each play, most recent last
+    undo: Vec<Game<StandardScorer>>,
+}
+
+/// Every game being played, by id
+struct Sessions {
+    next_id: u64,
+    games: HashMap<u64, Session>,
+}
+
+#[derive(Deserialize, Default)]
+struct NewGameRequest {
+    /// Seed used to deal the board, so the same board can be dealt again
+    seed: Option<u64>,
+    /// Seconds allowed to clear the board, at least one
+    time_limit: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct MoveRequest {
+    /// Labels of the stacks to play from, such as `TL`
+    positions: Vec<String>,
+    /// Hand to play, or the highest ranked hand the cards make if omitted
+    hand: Option<MoveType>,
+}
+
+#[derive(Serialize)]
+struct StackState {
+    position: String,
+    /// Code of the card on top of the stack, if any remain
+    top: Option<String>,
+    count: usize,
+    /// Points awarded for clearing the stack
+    bonus: i32,
+}
+
+#[derive(Serialize)]
+struct GameState {
+    id: u64,
+    seed: Option<u64>,
+    status: GameStatus,
+    score: i32,
+    lucky_card: String,
+    discards_allowed: i32,
+    discards_allowed_max: i32,
+    stacks: Vec<StackState>,
+    moves_remaining: bool,
+    /// Seconds spent playing
+    elapsed: u64,
+    /// Seconds left to clear the board, if the game is timed
+    time_remaining: Option<u64>,
+    /// Whether there is a play to undo
+    can_undo: bool,
+}
+
+#[derive(Serialize)]
+struct PlayState {
+    hand: MoveType,
+    cards: Vec<String>,
+    cleared_positions: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct CheckResult {
+    /// Highest ranked hand the cards make
+    hand: MoveType,
+    /// Every hand the cards make
+    hands: Vec<MoveType>,
+    score: Score,
+    points: i32,
+}
+
+#[derive(Serialize)]
+struct PlayResult {
+    play: PlayState,
+    points: i32,
+    game: GameState,
+}
+
+#[derive(Serialize)]
+struct ScoreState {
+    score: i32,
+    plays: Vec<PlayState>,
+}
+
+#[derive(Serialize)]
+struct ErrorMessage {
+    error: String,
+}
+
+/// Response status and JSON body
+type Reply = (u16, String);
+
+/// Serve games over HTTP on localhost until the process is stopped.
+/// Arguments are those following the `serve` subcommand.
+///
+/// Endpoints:
+/// - `POST /games`: Create a game, optionally with a `seed` and `time_limit`
+/// - `GET /games/ID`: Get the state of a game
+/// - `DELETE /games/ID`: End a game
+/// - `POST /games/ID/check`: Find the hands made by a set of `positions`
+/// - `POST /games/ID/play`: Play a `hand` from a set of `positions`
+/// - `POST /games/ID/undo`: Take back the last play
+/// - `GET /games/ID/score`: Get the score and every play made
+pub fn run(args: &[String]) -> Result<(), String> {
+    let port = parse_port(args)?;
+    let server = Server::http(("127.0.0.1", port)).map_err(|e| e.to_string())?;
+    let address = server.server_addr().to_ip().ok_or(USAGE.to_string())?;
+    println!("Listening on http://{}", address);
+    let server = Arc::new(server);
+    let sessions = Arc::new(Mutex::new(Sessions { next_id: 1, games: HashMap::new() }));
+    let workers: Vec<thread::JoinHandle<()>> = (0..WORKER_COUNT).map(|_| {
+        let server = server.clone();
+        let sessions = sessions.clone();
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                respond(request, &sessions);
+            }
+        })
+    }).collect();
+    for worker in workers {
+        worker.join().map_err(|_| "A server thread stopped unexpectedly".to_string())?;
+    }
+    return Ok(())
+}
+
+fn parse_port(args: &[String]) -> Result<u16, String> {
+    let mut port = DEFAULT_PORT;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = iter.next().ok_or(USAGE.to_string())?;
+        match arg.as_str() {
+            "--port" => port = value.parse().map_err(|_| USAGE.to_string())?,
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    return Ok(port)
+}
+
+/// Answer a request with the result of its endpoint
+fn respond(mut request: Request, sessions: &Mutex<Sessions>) {
+    let mut body = String::new();
+    let (status, content) = if request.as_reader().read_to_string(&mut body).is_err() {
+        error(400, "The request body is not UTF-8")
+    } else {
+        let mut sessions = sessions.lock().unwrap_or_else(|e| e.into_inner());
+        route(&mut sessions, request.method(), request.url(), &body)
+    };
+    let content_type = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap();
+    let response = Response::from_string(content)
+        .with_status_code(status)
+        .with_header(content_type);
+    // The client may have disconnected, which leaves no one to tell
+    request.respond(response).ok();
+}
+
+/// Find and run the endpoint for a method and path
+fn route(sessions: &mut Sessions, method: &Method, url: &str, body: &str) -> Reply {
+    let path = url.split('?').next().unwrap_or("");
+    let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
+    if parts.first() != Some(&"games") {
+        return error(404, "No such endpoint")
+    }
+    if parts.len() == 1 {
+        return match *method {
+            Method::Post => create_game(sessions, body),
+            _ => error(405, "Games can only be created with POST"),
+        }
+    }
+    let id = match parts[1].parse::<u64>() {
+        Ok(id) if sessions.games.contains_key(&id) => id,
+        _ => return error(404, &format!("No game has the id '{}'", parts[1])),
+    };
+    if *method == Method::Delete && parts.len() == 2 {
+        let session = sessions.games.remove(&id).unwrap();
+        return (200, encode(&game_state(id, &session)))
+    }
+    let session = sessions.games.get_mut(&id).unwrap();
+    let reply = match (method, &parts[2..]) {
+        (&Method::Get, []) => Ok((200, encode(&game_state(id, session)))),
+        (&Method::Post, ["check"]) => check(session, body),
+        (&Method::Post, ["play"]) => play(id, session, body),
+        (&Method::Post, ["undo"]) => undo(id, session),
+        (&Method::Get, ["score"]) => Ok((200, encode(&score_state(session)))),
+        (_, [_]) | (_, []) => Err(error(405, "This method is not allowed here")),
+        _ => Err(error(404, "No such endpoint")),
+    };
+    return reply.unwrap_or_else(|e| e)
+}
+
+fn create_game(sessions: &mut Sessions, body: &str) -> Reply {
+    let request: NewGameRequest = match decode(body) {
+        Ok(request) => request.unwrap_or_default(),
+        Err(reply) => return reply,
+    };
+    if request.time_limit == Some(0) {
+        return error(400, "The time limit must be at least one second")
+    }
+    let board = match request.seed {
+        Some(seed) => Board::with_seed(seed),
+        None => Board::new(),
+    };
+    let mut game = Game::with_board(board);
+    game.time_limit = request.time_limit.map(Duration::from_secs);
+    let id = sessions.next_id;
+    sessions.next_id += 1;
+    let session = Session { game: game, seed: request.seed, undo: vec![] };
+    let state = game_state(id, &session);
+    sessions.games.insert(id, session);
+    return (201, encode(&state))
+}
+
+fn check(session: &mut Session, body: &str) -> Result<Reply, Reply> {
+    let request: MoveRequest = decode(body)?.ok_or(error(400, "Positions are required"))?;
+    let positions = parse_positions(&request.positions)?;
+    let game = &session.game;
+    let hands = game.check_all(&positions).map_err(move_error)?;
+    let hand = request.hand.unwrap_or(hands[0]);
+    if !hands.contains(&hand) {
+        return Err(move_error(MoveError::InvalidHand))
+    }
+    let score = game.check_score(hand, &positions).map_err(move_error)?;
+    let points = score.total();
+    let result = CheckResult { hand: hand, hands: hands, score: score, points: points };
+    return Ok((200, encode(&result)))
+}
+
+fn play(id: u64, session: &mut Session, body: &str) -> Result<Reply, Reply> {
+    let request: MoveRequest = decode(body)?.ok_or(error(400, "Positions are required"))?;
+    let positions = parse_positions(&request.positions)?;
+    let hand = match request.hand {
+        Some(hand) => hand,
+        None => session.game.check(&positions).map_err(move_error)?,
+    };
+    let points = session.game.check_score(hand, &positions).map_err(move_error)?.total();
+    let before = session.game.clone();
+    let play = session.game.play(hand, &positions).map_err(move_error)?;
+    session.undo.push(before);
+    let result = PlayResult { play: play_state(&play), points: points, game: game_state(id, session) };
+    return Ok((200, encode(&result)))
+}
+
+fn undo(id: u64, session: &mut Session) -> Result<Reply, Reply> {
+    session.game = session.undo.pop().ok_or(error(409, "There is no play to undo"))?;
+    return Ok((200, encode(&game_state(id, session))))
+}
+
+fn game_state(id: u64, session: &Session) -> GameState {
+    let game = &session.game;
+    let stacks = game.board.positions().iter().map(|position| StackState {
+        position: format_position(*position),
+        top: game.board.top(*position).map(|card| format(card, CardStyle::Ascii)),
+        count: game.board.count_cards(*position),
+        bonus: game.scorer.bonus(*position),
+    }).collect();
+    return GameState {
+        id: id,
+        seed: session.seed,
+        status: game.status,
+        score: game.score(),
+        lucky_card: format(game.board.lucky_card, CardStyle::Ascii),
+        discards_allowed: game.discards_allowed,
+        discards_allowed_max: game.discards_allowed_max,
+        stacks: stacks,
+        moves_remaining: game.moves_remaining(),
+        elapsed: game.elapsed().as_secs(),
+        time_remaining: game.time_remaining().map(|t| t.as_secs()),
+        can_undo: !session.undo.is_empty(),
+    }
+}
+
+fn score_state(session: &Session) -> ScoreState {
+    return ScoreState {
+        score: session.game.score(),
+        plays: session.game.plays.iter().map(play_state).collect(),
+    }
+}
+
+fn play_state(play: &Play) -> PlayState {
+    return PlayState {
+        hand: play.hand,
+        cards: play.cards.iter().map(|card| format(*card, CardStyle::Ascii)).collect(),
+        cleared_positions: play.cleared_positions.iter().map(|p| format_position(*p)).collect(),
+    }
+}
+
+fn parse_positions(labels: &Vec<String>) -> Result<Vec<Position>, Reply> {
+    return labels.iter()
+        .map(|label| parse_position(label)
+             .ok_or(error(400, &format!("'{}' is not a stack position", label))))
+        .collect()
+}
+
+/// Read a JSON request body, or None if the body is empty
+fn decode<'a, T: Deserialize<'a>>(body: &'a str) -> Result<Option<T>, Reply> {
+    if body.trim().is_empty() {
+        return Ok(None)
+    }
+    return serde_json::from_str(body)
+        .map(Some)
+        .map_err(|e| error(400, &format!("The request body is not valid: {}", e)))
+}
+
+fn encode<T: Serialize>(value: &T) -> String {
+    return serde_json::to_string(value).expect("Responses can always be written as JSON")
+}
+
+fn move_error(code: MoveError) -> Reply {
+    return error(422, &code.to_string())
+}
+
+fn error(status: u16, message: &str) -> Reply {
+    return (status, encode(&ErrorMessage { error: message.to_string() }))
+}
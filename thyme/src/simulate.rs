@@ -0,0 +1,235 @@
+use libthyme::game::{Game,GameStatus,MoveType};
+use libthyme::score::StandardScorer;
+use libthyme::strategy::{STRATEGIES,Strategy,strategy_named};
+
+use hand_message;
+
+const USAGE: &str = "Usage: thyme simulate [--games N] [--strategy NAME] [--format table|csv]";
+
+/// Every hand type, in order of rank
+const HANDS: [MoveType; 9] = [
+    MoveType::StraightFlush,
+    MoveType::FourOfAKind,
+    MoveType::Flush,
+    MoveType::FullHouse,
+    MoveType::FiveCardStraight,
+    MoveType::ThreeOfAKind,
+    MoveType::ThreeCardStraight,
+    MoveType::Pair,
+    MoveType::Trash,
+];
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Format {
+    Table,
+    Csv,
+}
+
+struct Options {
+    games: usize,
+    strategy: &'static str,
+    format: Format,
+}
+
+/// Result of a single simulated game
+struct Outcome {
+    won: bool,
+    score: i32,
+    discards_used: usize,
+    hands: Vec<MoveType>,
+}
+
+/// Play a number of games headlessly and print statistics about the results.
+/// Arguments are those following the `simulate` subcommand.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let strategy = strategy_named(options.strategy).ok_or(USAGE.to_string())?;
+    let outcomes: Vec<Outcome> = (0..options.games)
+        .map(|_| play_game(strategy.as_ref()))
+        .collect();
+    let report = match options.format {
+        Format::Table => format_table(&options, &outcomes),
+        Format::Csv => format_csv(&options, &outcomes),
+    };
+    print!("{}", report);
+    return Ok(())
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options { games: 1000, strategy: STRATEGIES[0], format: Format::Table };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = iter.next().ok_or(USAGE.to_string())?;
+        match arg.as_str() {
+            "--games" => options.games = value.parse().map_err(|_| USAGE.to_string())?,
+            "--strategy" => options.strategy = STRATEGIES.iter()
+                .find(|name| **name == value.as_str())
+                .ok_or(USAGE.to_string())?,
+            "--format" => options.format = match value.as_str() {
+                "table" => Format::Table,
+                "csv" => Format::Csv,
+                _ => return Err(USAGE.to_string()),
+            },
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    return Ok(options)
+}
+
+/// Play a new game until no moves remain
+fn play_game(strategy: &dyn Strategy<StandardScorer>) -> Outcome {
+    let mut game = Game::<StandardScorer>::new();
+    let mut hands = vec![];
+    while let Some((hand, positions)) = strategy.choose(&game) {
+        game.play(hand, &positions).expect("Strategies only choose available moves");
+        hands.push(hand);
+    }
+    return Outcome {
+        won: game.status == GameStatus::Won,
+        score: game.score(),
+        discards_used: hands.iter().filter(|&&h| h == MoveType::Trash).count(),
+        hands: hands,
+    }
+}
+
+/// Value at a percentile of sorted scores
+fn percentile(scores: &Vec<i32>, percent: usize) -> i32 {
+    if scores.is_empty() {
+        return 0
+    }
+    return scores[(scores.len() - 1) * percent / 100]
+}
+
+/// Labelled statistics describing a set of outcomes
+fn summarize(options: &Options, outcomes: &Vec<Outcome>) -> Vec<(String, String)> {
+    let games = outcomes.len();
+    let per_game = |total: usize| if games > 0 { total as f64 / games as f64 } else { 0.0 };
+    let wins = outcomes.iter().filter(|o| o.won).count();
+    let mut scores: Vec<i32> = outcomes.iter().map(|o| o.score).collect();
+    scores.sort();
+    let total_score = scores.iter().fold(0, |acc, s| acc + *s as usize);
+    let discards = outcomes.iter().fold(0, |acc, o| acc + o.discards_used);
+    let mut rows = vec![
+        ("Strategy".to_string(), options.strategy.to_string()),
+        ("Games".to_string(), format!("{}", games)),
+        ("Wins".to_string(), format!("{}", wins)),
+        ("Win rate".to_string(), format!("{:.1}%", per_game(wins) * 100.0)),
+        ("Mean score".to_string(), format!("{:.1}", per_game(total_score))),
+        ("Min score".to_string(), format!("{}", percentile(&scores, 0))),
+        ("25th percentile score".to_string(), format!("{}", percentile(&scores, 25))),
+        ("Median score".to_string(), format!("{}", percentile(&scores, 50))),
+        ("75th percentile score".to_string(), format!("{}", percentile(&scores, 75))),
+        ("Max score".to_string(), format!("{}", percentile(&scores, 100))),
+        ("Average discards used".to_string(), format!("{:.2}", per_game(discards))),
+    ];
+    for hand in HANDS.iter() {
+        let count = outcomes.iter().fold(0, |acc, o| acc + o.hands.iter().filter(|h| *h == hand).count());
+        rows.push((format!("{} per game", hand_message(*hand)), format!("{:.2}", per_game(count))));
+    }
+    return rows
+}
+
+fn format_table(options: &Options, outcomes: &Vec<Outcome>) -> String {
+    let rows = summarize(options, outcomes);
+    let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+    return rows.iter()
+        .map(|(label, value)| format!("{:<width$}  {}\n", label, value, width = width))
+        .collect()
+}
+
+fn format_csv(options: &Options, outcomes: &Vec<Outcome>) -> String {
+    let mut output = "metric,value\n".to_string();
+    for (label, value) in summarize(options, outcomes) {
+        output.push_str(&format!("{},{}\n", label, value));
+    }
+    return output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        return values.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn outcome(won: bool, score: i32, hands: Vec<MoveType>) -> Outcome {
+        let discards_used = hands.iter().filter(|&&h| h == MoveType::Trash).count();
+        return Outcome { won: won, score: score, discards_used: discards_used, hands: hands }
+    }
+
+    fn options() -> Options {
+        return Options { games: 2, strategy: "greedy", format: Format::Table }
+    }
+
+    #[test]
+    fn parses_default_options() {
+        let options = parse_options(&[]).unwrap();
+        assert_eq!(options.games, 1000);
+        assert_eq!(options.strategy, "greedy");
+        assert_eq!(options.format, Format::Table);
+    }
+
+    #[test]
+    fn parses_options() {
+        let options = parse_options(&args(&["--games", "5", "--strategy", "random", "--format", "csv"])).unwrap();
+        assert_eq!(options.games, 5);
+        assert_eq!(options.strategy, "random");
+        assert_eq!(options.format, Format::Csv);
+    }
+
+    #[test]
+    fn rejects_invalid_options() {
+        assert!(parse_options(&args(&["--games"])).is_err());
+        assert!(parse_options(&args(&["--games", "many"])).is_err());
+        assert!(parse_options(&args(&["--strategy", "cheat"])).is_err());
+        assert!(parse_options(&args(&["--format", "xml"])).is_err());
+        assert!(parse_options(&args(&["--seed", "1"])).is_err());
+    }
+
+    #[test]
+    fn percentile_picks_from_sorted_scores() {
+        let scores = vec![10, 20, 30, 40, 50];
+        assert_eq!(percentile(&scores, 0), 10);
+        assert_eq!(percentile(&scores, 25), 20);
+        assert_eq!(percentile(&scores, 50), 30);
+        assert_eq!(percentile(&scores, 75), 40);
+        assert_eq!(percentile(&scores, 100), 50);
+        assert_eq!(percentile(&vec![], 50), 0);
+    }
+
+    #[test]
+    fn formats_csv() {
+        let outcomes = vec![
+            outcome(true, 300, vec![MoveType::Pair, MoveType::Trash]),
+            outcome(false, 100, vec![MoveType::Pair]),
+        ];
+        let csv = format_csv(&options(), &outcomes);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "metric,value");
+        assert!(lines.contains(&"Wins,1"));
+        assert!(lines.contains(&"Win rate,50.0%"));
+        assert!(lines.contains(&"Mean score,200.0"));
+        assert!(lines.contains(&"Max score,300"));
+        assert!(lines.contains(&"Average discards used,0.50"));
+        assert!(lines.contains(&"Pair per game,1.00"));
+        assert_eq!(lines.len(), 12 + HANDS.len());
+    }
+
+    #[test]
+    fn formats_table_in_aligned_columns() {
+        let table = format_table(&options(), &vec![outcome(true, 300, vec![])]);
+        // Every value starts in the column after the longest label
+        let columns: Vec<usize> = table.lines().map(|line| line.trim_end().rfind("  ").unwrap() + 2).collect();
+        assert!(columns.iter().all(|c| *c == columns[0]));
+        assert!(table.starts_with("Strategy"));
+        assert!(table.contains("Win rate"));
+    }
+
+    #[test]
+    fn summarizes_no_games() {
+        let rows = summarize(&options(), &vec![]);
+        assert!(rows.contains(&("Win rate".to_string(), "0.0%".to_string())));
+        assert!(rows.contains(&("Median score".to_string(), "0".to_string())));
+    }
+}
@@ -0,0 +1,259 @@
+use libthyme::game::Game;
+use ui::renderer::{initialize_colors,cleanup};
+use ui::screen::{AnsiScreen,Input,ERR,KEY_CLOSED,KEY_DOWN,KEY_LEFT,KEY_OTHER,KEY_RESIZE,
+                 KEY_RIGHT,KEY_UP};
+use std::collections::VecDeque;
+use std::io::{ErrorKind,Read,Write};
+use std::net::{TcpListener,TcpStream};
+use std::thread;
+use std::time::{Duration,Instant};
+
+use {parse_time_limit,play_game};
+
+const USAGE: &str = "Usage: thyme telnet [--port PORT] [--time-limit SECONDS] [--no-animation]";
+
+/// Port the server listens on unless another is given
+pub const DEFAULT_PORT: u16 = 2323;
+
+/// Size (lines, columns) assumed for clients which do not report their size
+const DEFAULT_SIZE: (i32, i32) = (24, 80);
+
+/// Milliseconds to wait for the rest of an escape sequence before reading
+/// escape as a key press
+const ESCAPE_DELAY: u64 = 25;
+
+const IAC: u8 = 255;
+const DONT: u8 = 254;
+const DO: u8 = 253;
+const WONT: u8 = 252;
+const WILL: u8 = 251;
+const SB: u8 = 250;
+const IP: u8 = 244;
+const SE: u8 = 240;
+const ECHO: u8 = 1;
+const SUPPRESS_GO_AHEAD: u8 = 3;
+const NAWS: u8 = 31;
+
+/// Host games over TCP, giving everyone who connects with a telnet client
+/// their own game until they quit or disconnect.
+/// Arguments are those following the `telnet` subcommand.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut port = DEFAULT_PORT;
+    let mut time_limit = None;
+    let mut animate = true;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--no-animation" {
+            animate = false;
+            continue;
+        }
+        let value = iter.next().ok_or(USAGE.to_string())?;
+        match arg.as_str() {
+            "--port" => port = value.parse().map_err(|_| USAGE.to_string())?,
+            "--time-limit" => time_limit = Some(parse_time_limit(value).ok_or(USAGE.to_string())?),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let listener = TcpListener::bind(("0.0.0.0", port)).map_err(|e| e.to_string())?;
+    let address = listener.local_addr().map_err(|e| e.to_string())?;
+    println!("Listening on port {}", address.port());
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(_) => continue,
+        };
+        thread::spawn(move || {
+            let peer = stream.peer_addr().map(|a| a.to_string()).unwrap_or_default();
+            println!("{} connected", peer);
+            if let Err(error) = host_game(stream, time_limit, animate) {
+                println!("{} failed: {}", peer, error);
+            }
+            println!("{} disconnected", peer);
+        });
+    }
+    return Ok(())
+}
+
+/// Play a game with a telnet client until the player quits or disconnects
+fn host_game(stream: TcpStream, time_limit: Option<Duration>, animate: bool) -> Result<(), String> {
+    let mut screen = open_screen(stream)?;
+    let mut game = Game::new();
+    game.time_limit = time_limit;
+    play_game(&mut screen, game, animate, None);
+    cleanup(&mut screen);
+    return Ok(())
+}
+
+/// Set up a telnet client to be drawn on and read keys from as a screen
+pub fn open_screen(mut stream: TcpStream) -> Result<AnsiScreen<TcpStream, TelnetInput>, String> {
+    stream.set_nodelay(true).map_err(|e| e.to_string())?;
+    // Echo keys and send each key as it is pressed rather than a line at a
+    // time, and report the size of the client's window
+    stream.write_all(&[IAC, WILL, ECHO, IAC, WILL, SUPPRESS_GO_AHEAD, IAC, DO, NAWS])
+        .map_err(|e| e.to_string())?;
+    let input = TelnetInput::new(stream.try_clone().map_err(|e| e.to_string())?);
+    let mut screen = AnsiScreen::new(stream, input);
+    initialize_colors(&mut screen);
+    return Ok(screen)
+}
+
+/// Keys read from a telnet client, decoding the escape sequences sent for
+/// arrow keys and the commands sent by the client
+pub struct TelnetInput {
+    stream: TcpStream,
+    /// Bytes received but not yet decoded
+    pending: VecDeque<u8>,
+    size: (i32, i32),
+    closed: bool,
+}
+
+impl TelnetInput {
+
+    fn new(stream: TcpStream) -> TelnetInput {
+        TelnetInput {
+            stream: stream,
+            pending: VecDeque::new(),
+            size: DEFAULT_SIZE,
+            closed: false,
+        }
+    }
+
+    /// Next byte received before a deadline, waiting indefinitely if there
+    /// is none, or None if the deadline passed or the client disconnected
+    fn next_byte(&mut self, deadline: Option<Instant>) -> Option<u8> {
+        while self.pending.is_empty() && !self.closed {
+            let timeout = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining == Duration::from_millis(0) {
+                        return None
+                    }
+                    Some(remaining)
+                },
+                None => None,
+            };
+            if self.stream.set_read_timeout(timeout).is_err() {
+                self.closed = true;
+                break
+            }
+            let mut buffer = [0; 256];
+            match self.stream.read(&mut buffer) {
+                Ok(0) => self.closed = true,
+                Ok(count) => self.pending.extend(&buffer[..count]),
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => {
+                    return None
+                },
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {},
+                Err(_) => self.closed = true,
+            }
+        }
+        return self.pending.pop_front()
+    }
+
+    /// Read the rest of a telnet command, returning `KEY_RESIZE` if the
+    /// client reported a new window size or `KEY_CLOSED` if it interrupted
+    /// the game
+    fn read_command(&mut self) -> Option<i32> {
+        let deadline = Some(Instant::now() + Duration::from_millis(ESCAPE_DELAY));
+        return match self.next_byte(deadline)? {
+            IP => Some(KEY_CLOSED),
+            DO | DONT | WILL | WONT => {
+                self.next_byte(deadline);
+                None
+            },
+            SB => {
+                let mut data = vec![];
+                while let Some(byte) = self.next_byte(deadline) {
+                    if byte == IAC && self.next_byte(deadline) == Some(SE) {
+                        break
+                    }
+                    data.push(byte);
+                }
+                if data.len() == 5 && data[0] == NAWS {
+                    let cols = (data[1] as i32) << 8 | data[2] as i32;
+                    let lines = (data[3] as i32) << 8 | data[4] as i32;
+                    if cols > 0 && lines > 0 {
+                        self.size = (lines, cols);
+                        return Some(KEY_RESIZE)
+                    }
+                }
+                None
+            },
+            _ => None,
+        }
+    }
+
+    /// Read the rest of an escape sequence, returning escape if there is
+    /// none
+    fn read_escape(&mut self) -> i32 {
+        let deadline = Some(Instant::now() + Duration::from_millis(ESCAPE_DELAY));
+        match self.next_byte(deadline) {
+            Some(b'[') | Some(b'O') => {},
+            Some(_) => return KEY_OTHER,
+            None => return 27,
+        }
+        // Parameters are followed by a final byte identifying the key
+        while let Some(byte) = self.next_byte(deadline) {
+            match byte {
+                b'A' => return KEY_UP,
+                b'B' => return KEY_DOWN,
+                b'C' => return KEY_RIGHT,
+                b'D' => return KEY_LEFT,
+                0x40..=0x7e => return KEY_OTHER,
+                _ => {},
+            }
+        }
+        return KEY_OTHER
+    }
+}
+
+impl Input for TelnetInput {
+
+    fn read_key(&mut self, milliseconds: i32) -> i32 {
+        let deadline = if milliseconds < 0 {
+            None
+        } else {
+            Some(Instant::now() + Duration::from_millis(milliseconds as u64))
+        };
+        loop {
+            let byte = match self.next_byte(deadline) {
+                Some(byte) => byte,
+                None if self.closed => return KEY_CLOSED,
+                None => return ERR,
+            };
+            let key = match byte {
+                IAC => match self.read_command() {
+                    Some(key) => key,
+                    None => continue,
+                },
+                27 => self.read_escape(),
+                // Return is sent as a carriage return followed by a line
+                // feed or null
+                b'\r' => {
+                    if let Some(&next) = self.pending.front() {
+                        if next == b'\n' || next == 0 {
+                            self.pending.pop_front();
+                        }
+                    }
+                    10
+                },
+                0 => continue,
+                // Ctrl-C
+                3 => KEY_CLOSED,
+                _ => byte as i32,
+            };
+            if key == KEY_CLOSED {
+                self.closed = true;
+            }
+            return key
+        }
+    }
+
+    fn size(&self) -> (i32, i32) {
+        return self.size
+    }
+
+    fn close(&mut self) {
+        self.closed = true;
+    }
+}
@@ -0,0 +1,158 @@
+extern crate serde_json;
+
+use serde_json::Value;
+use std::io::{BufRead,BufReader,Read,Write};
+use std::net::TcpStream;
+use std::process::{Child,Command,Stdio};
+
+/// A server listening on a free port, stopped when dropped
+struct TestServer {
+    process: Child,
+    address: String,
+}
+
+impl TestServer {
+
+    fn start() -> TestServer {
+        let mut process = Command::new(env!("CARGO_BIN_EXE_thyme"))
+            .args(["serve", "--port", "0"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut line = String::new();
+        BufReader::new(process.stdout.take().unwrap()).read_line(&mut line).unwrap();
+        let address = line.trim().trim_start_matches("Listening on http://").to_string();
+        return TestServer { process: process, address: address }
+    }
+
+    /// Send a request, returning the response status and JSON body
+    fn request(&self, method: &str, path: &str, body: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(&self.address).unwrap();
+        write!(stream, "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+               method, path, self.address, body.len(), body).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let content = &response[response.find("\r\n\r\n").unwrap() + 4..];
+        return (status, serde_json::from_str(content).unwrap())
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.process.kill().ok();
+        self.process.wait().ok();
+    }
+}
+
+#[test]
+fn creates_games_with_ids() {
+    let server = TestServer::start();
+    let (status, first) = server.request("POST", "/games", "");
+    assert_eq!(status, 201);
+    let (_, second) = server.request("POST", "/games", "");
+    assert_eq!(first["id"], 1);
+    assert_eq!(second["id"], 2);
+    assert_eq!(first["status"], "InProgress");
+    assert_eq!(first["stacks"].as_array().unwrap().len(), 9);
+}
+
+#[test]
+fn rejects_zero_time_limit() {
+    let server = TestServer::start();
+    let (status, error) = server.request("POST", "/games", "{\"time_limit\": 0}");
+    assert_eq!(status, 400);
+    assert_eq!(error["error"], "The time limit must be at least one second");
+    let (status, game) = server.request("POST", "/games", "{\"time_limit\": 1}");
+    assert_eq!(status, 201);
+    assert_eq!(game["id"], 1);
+}
+
+#[test]
+fn deals_same_board_from_seed() {
+    let server = TestServer::start();
+    let (_, first) = server.request("POST", "/games", "{\"seed\": 7}");
+    let (_, second) = server.request("POST", "/games", "{\"seed\": 7}");
+    assert_eq!(first["seed"], 7);
+    assert_eq!(first["stacks"], second["stacks"]);
+    assert_eq!(first["lucky_card"], second["lucky_card"]);
+}
+
+#[test]
+fn checks_selection() {
+    let server = TestServer::start();
+    server.request("POST", "/games", "");
+    let (status, check) = server.request("POST", "/games/1/check", "{\"positions\": [\"TL\"]}");
+    assert_eq!(status, 200);
+    assert_eq!(check["hand"], "Trash");
+    let (status, error) = server.request("POST", "/games/1/check", "{\"positions\": [\"TL\", \"TR\"]}");
+    assert_eq!(status, 422);
+    assert_eq!(error["error"], "A hand must use cards from more than one row");
+}
+
+#[test]
+fn plays_and_undoes_hand() {
+    let server = TestServer::start();
+    server.request("POST", "/games", "");
+    let (status, result) = server.request("POST", "/games/1/play", "{\"positions\": [\"TL\"], \"hand\": \"Trash\"}");
+    assert_eq!(status, 200);
+    assert_eq!(result["play"]["hand"], "Trash");
+    assert_eq!(result["game"]["discards_allowed"], 1);
+    assert_eq!(result["game"]["stacks"][0]["count"], 7);
+    let (_, score) = server.request("GET", "/games/1/score", "");
+    assert_eq!(score["plays"].as_array().unwrap().len(), 1);
+    let (status, state) = server.request("POST", "/games/1/undo", "");
+    assert_eq!(status, 200);
+    assert_eq!(state["discards_allowed"], 2);
+    assert_eq!(state["stacks"][0]["count"], 8);
+    assert_eq!(state["can_undo"], false);
+    let (status, _) = server.request("POST", "/games/1/undo", "");
+    assert_eq!(status, 409);
+}
+
+#[test]
+fn keeps_sessions_apart() {
+    let server = TestServer::start();
+    server.request("POST", "/games", "");
+    server.request("POST", "/games", "");
+    server.request("POST", "/games/1/play", "{\"positions\": [\"BR\"], \"hand\": \"Trash\"}");
+    let (_, first) = server.request("GET", "/games/1", "");
+    let (_, second) = server.request("GET", "/games/2", "");
+    assert_eq!(first["stacks"][8]["count"], 1);
+    assert_eq!(second["stacks"][8]["count"], 2);
+}
+
+#[test]
+fn rejects_invalid_requests() {
+    let server = TestServer::start();
+    server.request("POST", "/games", "");
+    assert_eq!(server.request("GET", "/games/5", "").0, 404);
+    assert_eq!(server.request("POST", "/games/1/play", "{\"positions\": [\"XX\"]}").0, 400);
+    assert_eq!(server.request("POST", "/games/1/play", "not json").0, 400);
+    let (status, error) = server.request("POST", "/games/1/play", "{\"positions\": [\"TL\"], \"hand\": \"Flush\"}");
+    assert_eq!(status, 422);
+    assert_eq!(error["error"], "This selection does not match the hand");
+}
+
+#[test]
+fn rejects_repeated_positions() {
+    let server = TestServer::start();
+    server.request("POST", "/games", "");
+    let body = "{\"positions\": [\"TL\", \"ML\", \"TL\", \"TL\"]}";
+    let (status, error) = server.request("POST", "/games/1/check", body);
+    assert_eq!(status, 422);
+    assert_eq!(error["error"], "This selection is not a hand");
+    let (status, _) = server.request("POST", "/games/1/play", body);
+    assert_eq!(status, 422);
+    let (_, score) = server.request("GET", "/games/1/score", "");
+    assert_eq!(score["score"], 0);
+    assert_eq!(score["plays"].as_array().unwrap().len(), 0);
+}
+
+#[test]
+fn ends_games() {
+    let server = TestServer::start();
+    server.request("POST", "/games", "");
+    assert_eq!(server.request("DELETE", "/games/1", "").0, 200);
+    assert_eq!(server.request("GET", "/games/1", "").0, 404);
+}
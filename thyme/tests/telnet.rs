@@ -0,0 +1,116 @@
+use std::io::{BufRead,BufReader,Read,Write};
+use std::net::TcpStream;
+use std::process::{Child,ChildStdout,Command,Stdio};
+use std::time::{Duration,Instant};
+
+/// A server listening on a free port, stopped when dropped
+struct TestServer {
+    process: Child,
+    /// Kept open so the server can log connections
+    _output: BufReader<ChildStdout>,
+    port: u16,
+}
+
+impl TestServer {
+
+    /// Start a server running a subcommand, such as `telnet` or `race`
+    fn start(subcommand: &str, args: &[&str]) -> TestServer {
+        let mut process = Command::new(env!("CARGO_BIN_EXE_thyme"))
+            .arg(subcommand)
+            .args(["--port", "0", "--no-animation"])
+            .args(args)
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut line = String::new();
+        let mut output = BufReader::new(process.stdout.take().unwrap());
+        output.read_line(&mut line).unwrap();
+        let port = line.trim().trim_start_matches("Listening on port ").parse().unwrap();
+        return TestServer { process: process, _output: output, port: port }
+    }
+
+    fn connect(&self) -> TcpStream {
+        let stream = TcpStream::connect(("127.0.0.1", self.port)).unwrap();
+        stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+        return stream
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.process.kill().ok();
+        self.process.wait().ok();
+    }
+}
+
+/// Read from a stream until its output contains some text
+fn read_until(stream: &mut TcpStream, text: &str) -> String {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut output = vec![];
+    let mut buffer = [0; 4096];
+    while !String::from_utf8_lossy(&output).contains(text) {
+        assert!(Instant::now() < deadline, "'{}' was not sent", text);
+        let count = stream.read(&mut buffer).unwrap();
+        assert!(count > 0, "Connection closed before '{}' was sent", text);
+        output.extend(&buffer[..count]);
+    }
+    return String::from_utf8_lossy(&output).into_owned()
+}
+
+#[test]
+fn draws_game_for_each_client() {
+    let server = TestServer::start("telnet", &[]);
+    let mut first = server.connect();
+    let mut second = server.connect();
+    read_until(&mut first, "Score:");
+    read_until(&mut second, "Score:");
+}
+
+#[test]
+fn closes_connection_when_player_quits() {
+    let server = TestServer::start("telnet", &[]);
+    let mut stream = server.connect();
+    read_until(&mut stream, "Score:");
+    stream.write_all(b"q").unwrap();
+    read_until(&mut stream, "Quit");
+    stream.write_all(b"y").unwrap();
+    read_until(&mut stream, "\x1b[?1049l");
+    let mut rest = vec![];
+    stream.read_to_end(&mut rest).unwrap();
+}
+
+#[test]
+fn starts_race_once_every_player_joins() {
+    let server = TestServer::start("race", &["--players", "2", "--seed", "7"]);
+    let mut first = server.connect();
+    read_until(&mut first, "1 of 2 players have joined");
+    let mut second = server.connect();
+    read_until(&mut first, "Player 2: 0 (51 left)");
+    read_until(&mut second, "Player 1: 0 (51 left)");
+}
+
+#[test]
+fn turns_away_players_during_race() {
+    let server = TestServer::start("race", &["--players", "2"]);
+    let mut first = server.connect();
+    let mut second = server.connect();
+    read_until(&mut first, "Player 2:");
+    read_until(&mut second, "Player 1:");
+    let mut late = server.connect();
+    read_until(&mut late, "A race is under way");
+}
+
+#[test]
+fn shows_forfeit_when_player_leaves_race() {
+    let server = TestServer::start("race", &["--players", "2"]);
+    let mut first = server.connect();
+    let mut second = server.connect();
+    read_until(&mut first, "Player 2:");
+    read_until(&mut second, "Player 1:");
+    second.write_all(b"qy").unwrap();
+    second.read_to_end(&mut vec![]).unwrap();
+    // Only changed cells are sent, so report the window size to have the
+    // whole screen sent again
+    first.write_all(&[255, 250, 31, 0, 80, 0, 24, 255, 240]).unwrap();
+    read_until(&mut first, "Player 2: 0 (forfeited)");
+}
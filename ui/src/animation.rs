@@ -0,0 +1,71 @@
+extern crate cards;
+
+use self::cards::card::Card;
+use libthyme::board::Position;
+
+/// Milliseconds between frames of an animation
+pub const FRAME_DURATION: i32 = 30;
+
+const SLIDE_FRAMES: i32 = 8;
+const FLIP_FRAMES: i32 = 6;
+const FLASH_FRAMES: i32 = 12;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Effect {
+    /// A played card moving off the board
+    SlideOff(Card),
+    /// The card beneath a played card being turned face up
+    FlipUp,
+    /// The bonus awarded for clearing a stack
+    FlashBonus,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Animation {
+    pub effect: Effect,
+    /// Stack the effect is drawn over
+    pub position: Position,
+    /// Frames to wait before the effect starts
+    pub delay: i32,
+    /// Frames drawn so far, including the delay
+    pub frame: i32,
+}
+
+impl Animation {
+
+    /// Create the animations for playing a card from a stack, sliding the
+    /// card off and then flipping the next card or flashing the bonus if
+    /// the stack was cleared
+    pub fn play_card(card: Card, position: Position, cleared: bool) -> Vec<Animation> {
+        let reveal = if cleared { Effect::FlashBonus } else { Effect::FlipUp };
+        return vec![
+            Animation { effect: Effect::SlideOff(card), position: position, delay: 0, frame: 0 },
+            Animation { effect: reveal, position: position, delay: SLIDE_FRAMES, frame: 0 }]
+    }
+
+    /// Number of frames in the effect, excluding the delay
+    pub fn frame_count(&self) -> i32 {
+        return match self.effect {
+            Effect::SlideOff(_) => SLIDE_FRAMES,
+            Effect::FlipUp => FLIP_FRAMES,
+            Effect::FlashBonus => FLASH_FRAMES,
+        }
+    }
+
+    /// Whether the delay has passed
+    pub fn is_started(&self) -> bool {
+        return self.frame >= self.delay
+    }
+
+    /// Whether every frame has been drawn
+    pub fn is_finished(&self) -> bool {
+        return self.frame >= self.delay + self.frame_count()
+    }
+
+    /// Portion of the effect drawn so far, from 0 before it starts to 1 when
+    /// finished
+    pub fn progress(&self) -> f32 {
+        let frame = (self.frame - self.delay).max(0).min(self.frame_count());
+        return frame as f32 / self.frame_count() as f32
+    }
+}
@@ -0,0 +1,89 @@
+use super::Action;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum DialogKind {
+    /// A question answered with yes or no
+    Confirm,
+    /// A message dismissed with any key
+    Message,
+    /// A message which stays open until the game closes it
+    Notice,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Answer {
+    /// The question was confirmed or the message was dismissed
+    Yes,
+    /// The question was cancelled
+    No,
+}
+
+/// A box drawn over the board which takes all input until it is answered
+#[derive(PartialEq, Clone, Debug)]
+pub struct Dialog {
+    pub kind: DialogKind,
+    pub title: String,
+    pub message: String,
+    /// Whether the board is hidden while the dialog is open
+    pub hides_board: bool,
+}
+
+impl Dialog {
+
+    /// Create a question answered with yes or no
+    pub fn confirm(title: &str, message: &str) -> Dialog {
+        Dialog {
+            kind: DialogKind::Confirm,
+            title: title.to_string(),
+            message: message.to_string(),
+            hides_board: false,
+        }
+    }
+
+    /// Create a message dismissed with any key
+    pub fn message(title: &str, message: &str) -> Dialog {
+        Dialog {
+            kind: DialogKind::Message,
+            title: title.to_string(),
+            message: message.to_string(),
+            hides_board: false,
+        }
+    }
+
+    /// Create a message which no key closes
+    pub fn notice(title: &str, message: &str) -> Dialog {
+        Dialog {
+            kind: DialogKind::Notice,
+            title: title.to_string(),
+            message: message.to_string(),
+            hides_board: false,
+        }
+    }
+
+    /// Hide the board while the dialog is open
+    pub fn hiding_board(self) -> Dialog {
+        return Dialog { hides_board: true, ..self }
+    }
+
+    /// Keys which answer the dialog
+    pub fn prompt(&self) -> &str {
+        return match self.kind {
+            DialogKind::Confirm => "[Y]es   [N]o",
+            DialogKind::Message => "Press any key",
+            DialogKind::Notice => "",
+        }
+    }
+
+    /// Answer given by an action, or None if the action does not close the
+    /// dialog
+    pub fn answer(&self, action: &Action) -> Option<Answer> {
+        return match (self.kind, action) {
+            (_, Action::Idle) | (_, Action::Resize) | (_, Action::Disconnect) => None,
+            (DialogKind::Notice, _) => None,
+            (DialogKind::Message, _) => Some(Answer::Yes),
+            (DialogKind::Confirm, Action::Confirm) | (DialogKind::Confirm, Action::Play) => Some(Answer::Yes),
+            (DialogKind::Confirm, Action::Cancel) => Some(Answer::No),
+            (DialogKind::Confirm, _) => None,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use libthyme::board::{Position,HPosition,VPosition};
+use std::cmp;
+
+/// Rows used by the title above the board
+pub const TITLE_HEIGHT: i32 = 2;
+/// Rows used by the messages beneath the board
+pub const STATUS_HEIGHT: i32 = 2;
+/// Columns left blank beside the board
+pub const BOARD_MARGIN: i32 = 2;
+/// Columns used by each card in the tracker grid
+pub const TRACKER_CELL_WIDTH: i32 = 3;
+/// Columns used by the tracker grid, including the suit labels
+pub const TRACKER_WIDTH: i32 = 2 + TRACKER_CELL_WIDTH*13;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum LayoutSize {
+    /// Tall cards with wide spacing
+    Large,
+    /// Standard cards
+    Normal,
+    /// Single-line cards with shadows and card counts
+    Compact,
+    /// Single-line cards only
+    Mini,
+}
+
+/// Sizes and placement of everything drawn on the board
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Layout {
+    pub size: LayoutSize,
+    pub card_width: i32,
+    pub card_height: i32,
+    /// Space between stacks and around the edges of the board
+    pub card_margin: i32,
+    /// Thickness of the shadow drawn beside and beneath a stack
+    pub shadow_size: i32,
+    /// Rows used by the card count beneath a stack
+    pub badge_height: i32,
+    /// Location (x, y) of the top left corner of the board
+    pub origin: (i32, i32),
+    /// Location (x, y) of the tracker grid, if there is room for it
+    pub tracker: Option<(i32, i32)>,
+}
+
+impl Layout {
+
+    /// Create a layout of a size placed in the top left corner of the screen
+    pub fn new(size: LayoutSize) -> Layout {
+        let (card_width, card_height, card_margin, shadow_size, badge_height) = match size {
+            LayoutSize::Large => (9, 7, 3, 1, 1),
+            LayoutSize::Normal => (7, 5, 2, 1, 1),
+            LayoutSize::Compact => (5, 1, 2, 1, 1),
+            LayoutSize::Mini => (4, 1, 2, 0, 0),
+        };
+        Layout {
+            size: size,
+            card_width: card_width,
+            card_height: card_height,
+            card_margin: card_margin,
+            shadow_size: shadow_size,
+            badge_height: badge_height,
+            origin: (BOARD_MARGIN, TITLE_HEIGHT),
+            tracker: None,
+        }
+    }
+
+    /// Choose the largest layout which fits on a screen, centering the board
+    /// in any space left over, or None if the screen is too small for any
+    pub fn for_screen(lines: i32, cols: i32) -> Option<Layout> {
+        let sizes = [LayoutSize::Large, LayoutSize::Normal, LayoutSize::Compact, LayoutSize::Mini];
+        return sizes.iter()
+            .map(|size| Layout::new(*size))
+            .find(|layout| layout.min_lines() <= lines && layout.min_cols() <= cols)
+            .map(|layout| layout.centered(lines, cols))
+    }
+
+    /// Columns used by a card and its shadow
+    pub fn stack_width(&self) -> i32 {
+        return self.card_width + self.shadow_size
+    }
+
+    /// Rows used by a card, its shadow and card count
+    pub fn stack_height(&self) -> i32 {
+        return self.card_height + self.shadow_size + self.badge_height
+    }
+
+    /// Columns used by the board, including margins
+    pub fn board_width(&self) -> i32 {
+        return self.card_margin*4 + self.stack_width()*3
+    }
+
+    /// Rows used by the board, including margins
+    pub fn board_height(&self) -> i32 {
+        return self.card_margin*4 + self.stack_height()*3
+    }
+
+    /// Fewest lines needed to show the title, board and messages
+    pub fn min_lines(&self) -> i32 {
+        return TITLE_HEIGHT + self.board_height() + STATUS_HEIGHT
+    }
+
+    /// Fewest columns needed to show the board
+    pub fn min_cols(&self) -> i32 {
+        return BOARD_MARGIN*2 + self.board_width()
+    }
+
+    /// Location (x, y) of the top left corner of the card at a position
+    pub fn card_location(&self, position: Position) -> (i32, i32) {
+        let column = match position.x {
+            HPosition::Left => 0,
+            HPosition::Center => 1,
+            HPosition::Right => 2,
+        };
+        let row = match position.y {
+            VPosition::Top => 0,
+            VPosition::Middle => 1,
+            VPosition::Bottom => 2,
+        };
+        let (left, top) = self.origin;
+        return (left + self.card_margin + column*(self.stack_width() + self.card_margin),
+                top + self.card_margin + row*(self.stack_height() + self.card_margin))
+    }
+
+    /// Move the board and tracker grid to the middle of the screen
+    fn centered(self, lines: i32, cols: i32) -> Layout {
+        let tracker_offset = self.board_width() + self.card_margin;
+        let has_tracker = cols >= BOARD_MARGIN + tracker_offset + TRACKER_WIDTH;
+        let content_width = if has_tracker {
+            tracker_offset + TRACKER_WIDTH
+        } else {
+            self.board_width()
+        };
+        let x = cmp::max(BOARD_MARGIN, (cols - content_width)/2);
+        let y = TITLE_HEIGHT + cmp::max(0, (lines - self.min_lines())/2);
+        let tracker = if has_tracker {
+            Some((x + tracker_offset, y + self.card_margin))
+        } else {
+            None
+        };
+        return Layout { origin: (x, y), tracker: tracker, ..self }
+    }
+}
@@ -1,25 +1,71 @@
+pub mod animation;
+pub mod dialog;
+pub mod layout;
+pub mod log;
 pub mod renderer;
+pub mod screen;
+
+// The terminal is drawn with ncurses by default, or with crossterm when
+// built with the `crossterm` feature
+#[cfg(feature = "crossterm")]
+#[path = "terminal/crossterm.rs"]
+mod terminal;
+#[cfg(all(feature = "ncurses", not(feature = "crossterm")))]
+#[path = "terminal/ncurses.rs"]
+mod terminal;
+#[cfg(not(any(feature = "ncurses", feature = "crossterm")))]
+compile_error!("ui needs either the `ncurses` or the `crossterm` feature");
 
 extern crate libthyme;
 
-use libthyme::board::{Position,HPosition,VPosition};
+use animation::{Animation,FRAME_DURATION};
+use dialog::Dialog;
+use log::{LOG_CAPACITY,MessageLog};
+use libthyme::board::{Board,Position,HPosition,VPosition};
+use libthyme::race::Race;
+use libthyme::score::Play;
+use std::time::{Duration,Instant};
 
 pub struct UI {
     pub cursor_position: Position,
     pub message: String,
     pub selection: Vec<Position>,
+    /// Animations in progress, drawn over the board
+    pub animations: Vec<Animation>,
+    /// Whether plays are animated
+    pub animations_enabled: bool,
+    /// When the current frame of the animations began, if any are running
+    pub animation_clock: Option<Instant>,
+    /// Dialog drawn over the board, if one is open
+    pub dialog: Option<Dialog>,
+    /// Messages logged during the game
+    pub history: MessageLog,
+    /// Whether the log is drawn over the board
+    pub show_history: bool,
+    /// Standings of the race being played, if any, and the index of this
+    /// player in it
+    pub race: Option<(Race, usize)>,
 }
 
 pub enum Action {
+    Cancel,
+    Confirm,
     CursorDown,
     CursorLeft,
     CursorRight,
     CursorUp,
     Help,
     Hint,
+    /// Input was closed, such as by a remote player disconnecting
+    Disconnect,
+    /// No input was received before the input timeout
+    Idle,
+    NewGame,
+    Pause,
     Play,
     Quit,
     Resize,
+    ToggleHistory,
     ToggleSelection,
     Unknown,
 }
@@ -33,7 +79,77 @@ impl UI {
                 x: HPosition::Left,
                 y: VPosition::Top  },
             message: "".to_string(),
-            selection: vec![]
+            selection: vec![],
+            animations: vec![],
+            animations_enabled: true,
+            animation_clock: None,
+            dialog: None,
+            history: MessageLog::new(LOG_CAPACITY),
+            show_history: false,
+            race: None,
+        }
+    }
+
+    /// Show a message and keep it in the log
+    pub fn log(&mut self, message: String) {
+        self.history.push(&message);
+        self.message = message;
+    }
+
+    /// Animate a play of the selected cards, unless animations are disabled
+    pub fn animate_play(&mut self, board: &Board, play: &Play) {
+        if !self.animations_enabled {
+            return
+        }
+        // Cards are played in the order of the stacks on the board
+        let positions: Vec<Position> = board.positions().into_iter()
+            .filter(|p| self.selection.contains(p))
+            .collect();
+        for (card, position) in play.cards.iter().zip(positions.iter()) {
+            let cleared = play.cleared_positions.contains(position);
+            self.animations.extend(Animation::play_card(*card, *position, cleared));
+        }
+        if self.animation_clock.is_none() && self.is_animating() {
+            self.animation_clock = Some(Instant::now());
+        }
+    }
+
+    /// Whether any animation has frames left to draw
+    pub fn is_animating(&self) -> bool {
+        return !self.animations.is_empty()
+    }
+
+    /// Move every animation on by the frames which have passed since they
+    /// were last advanced, removing finished animations
+    pub fn advance_animations(&mut self) {
+        self.advance_animations_to(Instant::now());
+    }
+
+    /// Move every animation on by the frames which have passed by a moment
+    /// in time, removing finished animations
+    pub fn advance_animations_to(&mut self, now: Instant) {
+        let start = match self.animation_clock {
+            Some(start) => start,
+            None => return,
+        };
+        let frame_duration = Duration::from_millis(FRAME_DURATION as u64);
+        let frames = (now.saturating_duration_since(start).as_millis()/frame_duration.as_millis()) as i32;
+        for animation in self.animations.iter_mut() {
+            animation.frame += frames;
+        }
+        self.animations.retain(|a| !a.is_finished());
+        self.animation_clock = if self.is_animating() {
+            Some(start + frame_duration*frames as u32)
+        } else {
+            None
+        };
+    }
+
+    /// Keep every animation on its current frame, discarding the time which
+    /// has passed since they were last advanced
+    pub fn hold_animations(&mut self) {
+        if self.animation_clock.is_some() {
+            self.animation_clock = Some(Instant::now());
         }
     }
 
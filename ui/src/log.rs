@@ -0,0 +1,69 @@
+use std::collections::VecDeque;
+
+/// Most messages kept in the log of a game
+pub const LOG_CAPACITY: usize = 100;
+
+/// Messages from the most recent actions, such as plays and errors, which
+/// can be scrolled through a page at a time
+pub struct MessageLog {
+    messages: VecDeque<String>,
+    capacity: usize,
+    /// Number of messages scrolled back from the newest message
+    pub scroll: usize,
+}
+
+impl MessageLog {
+
+    /// Create an empty log keeping at most a number of messages, though
+    /// always at least the newest message
+    pub fn new(capacity: usize) -> MessageLog {
+        MessageLog {
+            messages: VecDeque::new(),
+            capacity: capacity.max(1),
+            scroll: 0,
+        }
+    }
+
+    /// Add a message, dropping the oldest message if the log is full and
+    /// scrolling back to the newest message
+    pub fn push(&mut self, message: &str) {
+        if message.is_empty() {
+            return
+        }
+        if self.messages.len() == self.capacity {
+            self.messages.pop_front();
+        }
+        self.messages.push_back(message.to_string());
+        self.scroll = 0;
+    }
+
+    /// Number of messages in the log
+    pub fn len(&self) -> usize {
+        return self.messages.len()
+    }
+
+    /// True if no messages have been logged
+    pub fn is_empty(&self) -> bool {
+        return self.messages.is_empty()
+    }
+
+    /// Messages shown in a page of a height after scrolling, oldest first
+    pub fn visible(&self, height: usize) -> Vec<&str> {
+        let end = self.messages.len() - self.scroll;
+        let start = end.saturating_sub(height);
+        return self.messages.range(start..end).map(|m| m.as_str()).collect()
+    }
+
+    /// Scroll back by a message, stopping when the oldest message is at the
+    /// top of a page of a height
+    pub fn scroll_up(&mut self, height: usize) {
+        if self.scroll + height < self.messages.len() {
+            self.scroll += 1;
+        }
+    }
+
+    /// Scroll forward by a message, stopping at the newest message
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+}
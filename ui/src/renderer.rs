@@ -1,18 +1,23 @@
 extern crate cards;
 extern crate libthyme;
-extern crate ncurses;
 
+use self::cards::card::{Card,Suit,Value};
 use super::{Action,UI};
-use libthyme::board::{Position,HPosition,VPosition};
-use libthyme::game::Game;
+use animation::{Animation,Effect};
+use dialog::Dialog;
+use libthyme::board::Position;
+use libthyme::card_code::{CardStyle,format_suit,format_value};
+use libthyme::game::{CardStatus,Game,GameStatus};
+use libthyme::race::Race;
 use libthyme::score::Scorer;
+use layout::{BOARD_MARGIN,STATUS_HEIGHT,TITLE_HEIGHT,TRACKER_CELL_WIDTH,Layout,LayoutSize};
+use log::MessageLog;
+use screen::{Attributes,Screen,BOLD,DIM,REVERSE,COLOR_BLACK,COLOR_BLUE,COLOR_CYAN,COLOR_GREEN,
+             COLOR_RED,COLOR_WHITE,COLOR_YELLOW,ERR,KEY_CLOSED,KEY_DOWN,KEY_ENTER,KEY_LEFT,
+             KEY_RESIZE,KEY_RIGHT,KEY_UP,color_pair};
+use terminal;
 use std::cmp;
-
-const CARD_WIDTH: i32 = 7;
-const CARD_HEIGHT: i32 = 5;
-const CARD_MARGIN: i32 = 2;
-const BOARD_MARGIN: i32 = 2;
-const STATUS_HEIGHT: i32 = 2;
+use std::time::Duration;
 
 const CARD_COLOR_BLACK: i16 = 1; // black on white
 const CARD_COLOR_RED: i16 = 2; // red on white
@@ -20,43 +25,70 @@ const CARD_COLOR_EMPTY: i16 = 4; // white on black
 const SELECTED_COLOR: i16 = 5; // yellow on black
 const CURSOR_INFO_COLOR: i16 = 6; // cyan on black
 const GAME_INFO_COLOR: i16 = 7; // green on black
-const BG_COLOR: i16 = ncurses::COLOR_BLACK;
-const CARD_BG_COLOR: i16 = ncurses::COLOR_WHITE;
+const TRASHED_COLOR: i16 = 8; // red on black
+const LUCKY_COLOR: i16 = 9; // blue on white
+const BG_COLOR: i16 = COLOR_BLACK;
+const CARD_BG_COLOR: i16 = COLOR_WHITE;
+
+/// Set up the UI in the terminal running the game
+pub fn initialize_screen() -> Box<dyn Screen> {
+    let mut screen = terminal::open();
+    initialize_colors(screen.as_mut());
+    return screen
+}
 
-/// Set up the UI
-pub fn initialize_screen() {
-    ncurses::setlocale(ncurses::LcCategory::ctype, "");
-    ncurses::initscr();
-    ncurses::noecho();
-    ncurses::start_color();
-    ncurses::keypad(ncurses::stdscr(), true);
-    ncurses::curs_set(ncurses::CURSOR_VISIBILITY::CURSOR_INVISIBLE);
-    ncurses::init_pair(CARD_COLOR_BLACK, ncurses::COLOR_BLACK, CARD_BG_COLOR);
-    ncurses::init_pair(CARD_COLOR_RED, ncurses::COLOR_RED, CARD_BG_COLOR);
-    ncurses::init_pair(CARD_COLOR_EMPTY, ncurses::COLOR_WHITE, BG_COLOR);
-    ncurses::init_pair(SELECTED_COLOR, ncurses::COLOR_YELLOW, BG_COLOR);
-    ncurses::init_pair(CURSOR_INFO_COLOR, ncurses::COLOR_CYAN, BG_COLOR);
-    ncurses::init_pair(GAME_INFO_COLOR, ncurses::COLOR_GREEN, BG_COLOR);
+/// Set up the colors used to draw a UI in a screen
+pub fn initialize_colors(screen: &mut dyn Screen) {
+    screen.init_pair(CARD_COLOR_BLACK, COLOR_BLACK, CARD_BG_COLOR);
+    screen.init_pair(CARD_COLOR_RED, COLOR_RED, CARD_BG_COLOR);
+    screen.init_pair(CARD_COLOR_EMPTY, COLOR_WHITE, BG_COLOR);
+    screen.init_pair(SELECTED_COLOR, COLOR_YELLOW, BG_COLOR);
+    screen.init_pair(CURSOR_INFO_COLOR, COLOR_CYAN, BG_COLOR);
+    screen.init_pair(GAME_INFO_COLOR, COLOR_GREEN, BG_COLOR);
+    screen.init_pair(TRASHED_COLOR, COLOR_RED, BG_COLOR);
+    screen.init_pair(LUCKY_COLOR, COLOR_BLUE, CARD_BG_COLOR);
 }
 
-/// Redraw a UI in the current screen
-pub fn redraw<T: Scorer>(ui: &UI, game: &mut Game<T>, refresh: bool) {
+/// Redraw a UI in a screen
+pub fn redraw<T: Scorer>(screen: &mut dyn Screen, ui: &UI, game: &mut Game<T>, refresh: bool) {
     if refresh {
-        ncurses::clear();
-        ncurses::bkgd(ncurses::COLOR_PAIR(CARD_COLOR_EMPTY));
+        screen.clear();
+        screen.bkgd(color_pair(CARD_COLOR_EMPTY));
+    } else {
+        // Moving cards and closed dialogs leave marks unless the screen is
+        // cleared
+        screen.erase();
     }
-    if validate_screen_size() {
-        write_title(game);
-        draw_cards(ui, game);
-        write_cursor_message(ui, game);
-        write_message(&ui.message);
+    match Layout::for_screen(screen.lines(), screen.cols()) {
+        Some(layout) => {
+            write_title(screen, &layout, ui, game);
+            if !ui.dialog.as_ref().is_some_and(|d| d.hides_board) {
+                draw_cards(screen, &layout, ui, game);
+                draw_tracker(screen, &layout, game);
+                write_cursor_message(screen, ui, game);
+            }
+            write_message(screen, &ui.message);
+            if ui.show_history {
+                draw_history(screen, &ui.history);
+            }
+            if let Some(ref dialog) = ui.dialog {
+                draw_dialog(screen, dialog);
+            }
+        },
+        None => write_message(screen, "Terminal too small"),
     }
-    ncurses::refresh();
+    screen.refresh();
 }
 
 /// Tear down the UI
-pub fn cleanup() {
-    ncurses::endwin();
+pub fn cleanup(screen: &mut dyn Screen) {
+    screen.end();
+}
+
+/// Wait at most a number of milliseconds for input when getting an action,
+/// or wait indefinitely if negative
+pub fn set_input_timeout(screen: &mut dyn Screen, milliseconds: i32) {
+    screen.timeout(milliseconds);
 }
 
 /// Process input from the user
@@ -64,241 +96,518 @@ pub fn cleanup() {
 /// Known inputs:
 /// - Arrow keys/WASD: Move cursor between various positions on the board
 /// - Q: Quit game
-/// - H: Hint (unimplemented)
+/// - T: Hint
+/// - P: Pause
+/// - M: Show or hide the message log
+/// - R: New game
+/// - Y/N/Escape: Confirm or cancel a dialog
 /// - ?: Help (unimplemented)
 /// - Space: Toggle position selection
 /// - Return: Play move, clear selection
-pub fn get_action() -> Action {
-    return match ncurses::getch() {
-        ncurses::KEY_LEFT | 97 | 104 => Action::CursorLeft,
-        ncurses::KEY_RIGHT | 100 | 108 => Action::CursorRight,
-        ncurses::KEY_UP | 119 | 107 => Action::CursorUp,
-        ncurses::KEY_DOWN | 115 | 106 => Action::CursorDown,
-        ncurses::KEY_RESIZE => Action::Resize,
-        ncurses::KEY_ENTER | 13 | 10 => Action::Play,
+pub fn get_action(screen: &mut dyn Screen) -> Action {
+    return match screen.getch() {
+        KEY_LEFT | 97 | 104 => Action::CursorLeft,
+        KEY_RIGHT | 100 | 108 => Action::CursorRight,
+        KEY_UP | 119 | 107 => Action::CursorUp,
+        KEY_DOWN | 115 | 106 => Action::CursorDown,
+        KEY_RESIZE => Action::Resize,
+        KEY_ENTER | 13 | 10 => Action::Play,
         32 =>  Action::ToggleSelection, // Space
         113 => Action::Quit, // Q
         116 => Action::Hint, // T
+        112 => Action::Pause, // P
+        109 => Action::ToggleHistory, // M
+        114 => Action::NewGame, // R
+        121 => Action::Confirm, // Y
+        110 | 27 => Action::Cancel, // N, Escape
         63 =>  Action::Help, // ?
+        ERR => Action::Idle,
+        KEY_CLOSED => Action::Disconnect,
         _ =>   Action::Unknown
     }
 }
 
-/// Check that the content can fit
-fn validate_screen_size() -> bool {
-    let min_height = BOARD_MARGIN*2 + CARD_MARGIN*4 + STATUS_HEIGHT + CARD_HEIGHT*3;
-    if ncurses::LINES() < min_height || ncurses::COLS() < 50 {
-        write_message(&format!(
-                "Please resize your terminal to be at least 50x{}",
-                min_height));
-        return false
+/// Print the game title and status info
+fn write_title<T: Scorer>(screen: &mut dyn Screen, layout: &Layout, ui: &UI, game: &mut Game<T>) {
+    addstr_margin(screen, 0, 0);
+    screen.attron(BOLD);
+    screen.addstr("Thyme");
+    screen.attroff(BOLD);
+    screen.addstr(&format!(" - Score: {}", game.score()));
+    let clock = match game.time_remaining() {
+        Some(remaining) => format!("  Time Left: {}", format_duration(remaining)),
+        None => format!("  Time: {}", format_duration(game.elapsed())),
+    };
+    let clock_color = if game.is_out_of_time() {
+        color_pair(TRASHED_COLOR) | BOLD
+    } else {
+        color_pair(CARD_COLOR_EMPTY)
+    };
+    addstr_color(screen, &clock, clock_color);
+    screen.clrtoeol();
+    addstr_margin(screen, 0, 1);
+    let (_, suit) = layout_suit(game.board.lucky_card);
+    let info = color_pair(GAME_INFO_COLOR);
+    screen.attron(info);
+    let text = if layout.size == LayoutSize::Mini {
+        format!("{} \u{2605}  Discards: {}/{}", suit,
+                game.discards_allowed, game.discards_allowed_max)
+    } else {
+        format!("Lucky Suit: {} \u{2605}  Discards Left: {}/{}", suit,
+                game.discards_allowed, game.discards_allowed_max)
+    };
+    screen.addstr(&text);
+    screen.clrtoeol();
+    screen.attroff(info);
+    if let Some((ref race, player)) = ui.race {
+        write_standings(screen, race, player, text.chars().count() as i32);
     }
-    return true
 }
 
-/// Print the game title and status info
-fn write_title<T: Scorer>(game: &mut Game<T>) {
-    addstr_margin(0, 0);
-    ncurses::attron(ncurses::A_BOLD());
-    ncurses::addstr("Thyme");
-    ncurses::attroff(ncurses::A_BOLD());
-    ncurses::addstr(&format!(" - Score: {}", game.score()));
-    addstr_margin(0, 1);
-    let (_, suit) = layout_suit(game.board.lucky_card);
-    let info = ncurses::COLOR_PAIR(GAME_INFO_COLOR);
-    ncurses::attron(info);
-    ncurses::addstr(&format!("Lucky Suit: {}  Discards Left: {}/{}", suit,
-                             game.discards_allowed, game.discards_allowed_max));
-    ncurses::clrtoeol();
-    ncurses::attroff(info);
+/// Print the progress of the other players in a race at the end of the
+/// second title line, if there is room beside the game info
+fn write_standings(screen: &mut dyn Screen, race: &Race, player: usize, info_width: i32) {
+    let standings: Vec<String> = race.racers.iter().enumerate()
+        .filter(|&(index, _)| index != player)
+        .map(|(_, racer)| {
+            let progress = if racer.forfeited {
+                "forfeited".to_string()
+            } else {
+                match racer.status {
+                    GameStatus::InProgress => format!("{} left", racer.cards_remaining),
+                    GameStatus::Won => "cleared".to_string(),
+                    GameStatus::Lost { cards_left } => format!("out, {} left", cards_left),
+                }
+            };
+            format!("{}: {} ({})", racer.name, racer.score, progress)
+        })
+        .collect();
+    let width = screen.cols() - BOARD_MARGIN*2 - info_width - 2;
+    if width <= 0 {
+        return
+    }
+    let text = truncate(&standings.join("  "), width as usize);
+    let x = screen.cols() - BOARD_MARGIN - text.chars().count() as i32;
+    screen.mv(1, x);
+    addstr_color(screen, &text, color_pair(CURSOR_INFO_COLOR));
+}
+
+/// Number of messages shown on a page of the message log
+pub fn history_page_size(screen: &dyn Screen) -> usize {
+    return cmp::max(1, screen.lines() - TITLE_HEIGHT - STATUS_HEIGHT - 3) as usize
+}
+
+/// Draw a page of the message log in a box over the board
+fn draw_history(screen: &mut dyn Screen, history: &MessageLog) {
+    let height = history_page_size(screen) as i32 + 3;
+    let width = screen.cols() - BOARD_MARGIN*2;
+    let (x, y) = (BOARD_MARGIN, TITLE_HEIGHT);
+    let color = color_pair(CARD_COLOR_EMPTY);
+    let border = color_pair(SELECTED_COLOR);
+    let visible = history.visible(history_page_size(screen));
+    let newest = history.len() - history.scroll;
+    let title = if history.is_empty() {
+        "Log - no messages yet".to_string()
+    } else {
+        format!("Log {}-{} of {} - \u{2191}/\u{2193} to scroll, M to close",
+                newest - visible.len() + 1, newest, history.len())
+    };
+    screen.mv(y, x);
+    addstr_color(screen, "┌", border);
+    addstr_repeat(screen, "─", width - 2, border);
+    addstr_color(screen, "┐", border);
+    for row in 1..height - 1 {
+        screen.mv(y + row, x);
+        addstr_color(screen, "│", border);
+        addstr_repeat(screen, " ", width - 2, color);
+        addstr_color(screen, "│", border);
+    }
+    screen.mv(y + height - 1, x);
+    addstr_color(screen, "└", border);
+    addstr_repeat(screen, "─", width - 2, border);
+    addstr_color(screen, "┘", border);
+    let text_width = cmp::max(0, width - 4) as usize;
+    screen.mv(y + 1, x + 2);
+    addstr_color(screen, &truncate(&title, text_width), color | BOLD);
+    for (i, message) in visible.iter().enumerate() {
+        screen.mv(y + 2 + i as i32, x + 2);
+        addstr_color(screen, &truncate(message, text_width), color);
+    }
+}
+
+/// Shorten a string to a number of characters
+fn truncate(content: &str, width: usize) -> String {
+    return content.chars().take(width).collect()
+}
+
+/// Draw a dialog in a box in the middle of the screen
+fn draw_dialog(screen: &mut dyn Screen, dialog: &Dialog) {
+    let lines = [dialog.title.as_str(), "", dialog.message.as_str(), "", dialog.prompt()];
+    let content_width = lines.iter().map(|l| l.chars().count() as i32).max().unwrap();
+    let width = cmp::min(content_width + 4, screen.cols());
+    let height = lines.len() as i32 + 2;
+    let x = cmp::max(0, (screen.cols() - width)/2);
+    let y = cmp::max(0, (screen.lines() - height)/2);
+    let color = color_pair(CARD_COLOR_EMPTY);
+    let border = color_pair(SELECTED_COLOR);
+    screen.mv(y, x);
+    addstr_color(screen, "┌", border);
+    addstr_repeat(screen, "─", width - 2, border);
+    addstr_color(screen, "┐", border);
+    for (i, line) in lines.iter().enumerate() {
+        let row = y + 1 + i as i32;
+        let lede = (width - 2 - line.chars().count() as i32)/2;
+        screen.mv(row, x);
+        addstr_color(screen, "│", border);
+        addstr_repeat(screen, " ", width - 2, color);
+        addstr_color(screen, "│", border);
+        let attributes = if i == 0 { color | BOLD } else { color };
+        screen.mv(row, x + 1 + cmp::max(0, lede));
+        addstr_color(screen, line, attributes);
+    }
+    screen.mv(y + height - 1, x);
+    addstr_color(screen, "└", border);
+    addstr_repeat(screen, "─", width - 2, border);
+    addstr_color(screen, "┘", border);
+}
+
+/// Time written as minutes and seconds, such as `2:05`
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    return format!("{}:{:02}", seconds / 60, seconds % 60)
 }
 
 /// Print the message at the bottom of the window
-fn write_message(message: &str) {
-    addstr_margin(0, ncurses::LINES() - 1);
-    ncurses::addstr(message);
-    ncurses::clrtoeol();
+fn write_message(screen: &mut dyn Screen, message: &str) {
+    let y = screen.lines() - 1;
+    addstr_margin(screen, 0, y);
+    screen.addstr(message);
+    screen.clrtoeol();
 }
 
 /// Print a message describing the stack above the cursor
-fn write_cursor_message<T: Scorer>(ui: &UI, game: &Game<T>) {
+fn write_cursor_message<T: Scorer>(screen: &mut dyn Screen, ui: &UI, game: &Game<T>) {
     let stacked_cards = game.board.count_cards(ui.cursor_position);
     let all_cards = game.board.count_all_cards();
     let message = format!("*{}/{} cards in the stack", stacked_cards, all_cards);
-    let color = ncurses::COLOR_PAIR(CURSOR_INFO_COLOR);
-    addstr_margin(0, ncurses::LINES() - 2);
-    ncurses::attron(color);
-    ncurses::addstr(&message);
-    ncurses::clrtoeol();
-    ncurses::attroff(color);
+    let color = color_pair(CURSOR_INFO_COLOR);
+    let y = screen.lines() - 2;
+    addstr_margin(screen, 0, y);
+    screen.attron(color);
+    screen.addstr(&message);
+    screen.clrtoeol();
+    screen.attroff(color);
 }
 
 /// Print spaces for the width of the board margin
-fn addstr_margin(x: i32, y: i32) {
-    ncurses::mv(y, x);
-    addstr_repeat(" ", BOARD_MARGIN, ncurses::COLOR_PAIR(CARD_COLOR_EMPTY));
+fn addstr_margin(screen: &mut dyn Screen, x: i32, y: i32) {
+    screen.mv(y, x);
+    addstr_repeat(screen, " ", BOARD_MARGIN, color_pair(CARD_COLOR_EMPTY));
 }
 
-/// Print the card values and empty stacks
-fn draw_cards<T: Scorer>(ui: &UI, game: &mut Game<T>) {
+/// Print the card values and empty stacks, followed by any cards sliding
+/// off the board
+fn draw_cards<T: Scorer>(screen: &mut dyn Screen, layout: &Layout, ui: &UI, game: &mut Game<T>) {
     for position in game.board.positions() {
+        let (x, y) = layout.card_location(position);
+        let reveal = ui.animations.iter()
+            .find(|a| a.position == position && !matches!(a.effect, Effect::SlideOff(_)));
         let card = game.board.top(position);
         if card.is_some() {
-            draw_card(position, card.unwrap());
+            let lucky = card.unwrap().suit == game.board.lucky_card.suit;
+            match reveal {
+                Some(flip) if flip.progress() < 0.5 => {
+                    let width = (layout.card_width as f32 * (1.0 - flip.progress()*2.0)).ceil() as i32;
+                    draw_card_back(screen, layout, x, y, width);
+                },
+                _ => draw_card(screen, layout, x, y, card.unwrap(), lucky),
+            }
         } else {
-            draw_empty(game, position);
+            let flash = reveal.is_some_and(|a| a.is_started() && (a.frame - a.delay)/2 % 2 == 0);
+            draw_empty(screen, layout, game, position, flash);
         }
-        let (x, y) = card_location(position);
-        toggle_highlight_card(x, y, ui.selection.contains(&position));
+        let count = game.board.count_cards(position);
+        draw_shadow(screen, layout, x, y, count);
+        draw_badge(screen, layout, x, y, count);
+        toggle_highlight_card(screen, layout, x, y, ui.selection.contains(&position));
         if position == ui.cursor_position {
-            let offset = (CARD_WIDTH as f32 /2.0).floor() as i32;
-            ncurses::mvprintw(y + CARD_HEIGHT, x + offset, "*");
+            let offset = (layout.card_width as f32 /2.0).floor() as i32;
+            screen.mvprintw(y + layout.card_height + layout.shadow_size, x + offset, "*");
+        }
+    }
+    for animation in ui.animations.iter() {
+        if let Effect::SlideOff(card) = animation.effect {
+            draw_sliding_card(screen, layout, animation, card, card.suit == game.board.lucky_card.suit);
+        }
+    }
+}
+
+/// Draw a played card part of the way across the board towards the right
+/// edge of the screen
+fn draw_sliding_card(screen: &mut dyn Screen, layout: &Layout, animation: &Animation, card: Card, lucky: bool) {
+    let (x, y) = layout.card_location(animation.position);
+    let distance = screen.cols() - x;
+    let offset = (distance as f32 * animation.progress()) as i32;
+    if x + offset + layout.card_width < screen.cols() {
+        draw_card(screen, layout, x + offset, y, card, lucky);
+    }
+}
+
+/// Draw the back of a card, narrowed to a width as if turning over
+fn draw_card_back(screen: &mut dyn Screen, layout: &Layout, x: i32, y: i32, width: i32) {
+    let back = color_pair(LUCKY_COLOR);
+    let blank = color_pair(CARD_COLOR_EMPTY);
+    let lede = (layout.card_width - width)/2;
+    for i in 0..layout.card_height {
+        screen.mv(y + i, x);
+        addstr_repeat(screen, " ", lede, blank);
+        addstr_repeat(screen, "\u{2592}", width, back);
+        addstr_repeat(screen, " ", layout.card_width - lede - width, blank);
+    }
+}
+
+/// Print a grid of every card beside the board, marking which cards have
+/// been played or trashed, which remain in the stacks and the lucky card.
+/// The grid is omitted if the screen is too narrow.
+fn draw_tracker<T: Scorer>(screen: &mut dyn Screen, layout: &Layout, game: &Game<T>) {
+    let suits = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+    let values = [Value::Ace, Value::Two, Value::Three, Value::Four, Value::Five,
+                  Value::Six, Value::Seven, Value::Eight, Value::Nine, Value::Ten,
+                  Value::Jack, Value::Queen, Value::King];
+    let (x, y) = match layout.tracker {
+        Some(location) => location,
+        None => return,
+    };
+    screen.mv(y - 1, x);
+    screen.attron(BOLD);
+    screen.addstr("Cards");
+    screen.attroff(BOLD);
+    for (row, suit) in suits.iter().enumerate() {
+        screen.mv(y + row as i32, x);
+        let (suit_color, symbol) = match *suit {
+            Suit::Hearts | Suit::Diamonds => (TRASHED_COLOR, format_suit(*suit, CardStyle::Unicode)),
+            _ => (CARD_COLOR_EMPTY, format_suit(*suit, CardStyle::Unicode)),
+        };
+        addstr_color(screen, &format!("{} ", symbol), color_pair(suit_color));
+        for value in values.iter() {
+            let card = Card { value: *value, suit: *suit };
+            let (label, color) = tracker_cell(game.card_status(card), format_value(*value));
+            addstr_color(screen, &format!("{:<width$}", label, width = TRACKER_CELL_WIDTH as usize), color);
+        }
+    }
+    let legend = [(CardStatus::Buried, "buried"), (CardStatus::Visible, "on top"),
+                  (CardStatus::Lucky, "lucky"), (CardStatus::Played, "played"),
+                  (CardStatus::Trashed, "trashed")];
+    for (i, &(status, name)) in legend.iter().enumerate() {
+        if i % 3 == 0 {
+            screen.mv(y + 5 + i as i32 / 3, x);
         }
+        let (label, color) = tracker_cell(status, "A".to_string());
+        addstr_color(screen, &label, color);
+        screen.addstr(&format!(" {}  ", name));
     }
 }
 
-/// Draw a card on the board at a position
-fn draw_card(position: Position, card: cards::card::Card) {
-    let (x, y) = card_location(position);
+/// Label and attributes of a card in the tracker grid
+fn tracker_cell(status: CardStatus, value: String) -> (String, Attributes) {
+    let normal = color_pair(CARD_COLOR_EMPTY);
+    return match status {
+        CardStatus::Buried => (value, normal),
+        CardStatus::Visible => (value, color_pair(CURSOR_INFO_COLOR) | BOLD),
+        CardStatus::Lucky => (value, color_pair(GAME_INFO_COLOR) | BOLD),
+        CardStatus::Played => ("\u{00b7}".to_string(), normal | DIM),
+        CardStatus::Trashed => ("\u{00d7}".to_string(), color_pair(TRASHED_COLOR)),
+        CardStatus::Absent => (" ".to_string(), normal),
+    }
+}
+
+/// Print a string using a set of attributes
+fn addstr_color(screen: &mut dyn Screen, content: &str, color: Attributes) {
+    screen.attron(color);
+    screen.addstr(content);
+    screen.attroff(color);
+}
+
+/// Draw a card on the board at a location, marking cards with the lucky
+/// suit with a star
+fn draw_card(screen: &mut dyn Screen, layout: &Layout, x: i32, y: i32, card: Card, lucky: bool) {
+    let (width, height) = (layout.card_width, layout.card_height);
     let (color, suit) = layout_suit(card);
-    let value = layout_value(card);
-    let black = ncurses::COLOR_PAIR(CARD_COLOR_BLACK);
-    ncurses::attron(black);
-    ncurses::mvprintw(y, x, &value);
-    ncurses::attroff(black);
-    ncurses::attron(color);
-    ncurses::mvprintw(y, x + value.len() as i32, &suit);
-    ncurses::attroff(color);
-    let spacing = CARD_WIDTH - value.len() as i32 - 1;
-    addstr_repeat(" ", spacing, black);
-    for i in 1..CARD_HEIGHT - 1 {
-        ncurses::mv(y + i, x);
-        addstr_repeat(" ", CARD_WIDTH, black);
+    let value = format_value(card.value);
+    let black = color_pair(CARD_COLOR_BLACK);
+    screen.attron(black);
+    screen.mvprintw(y, x, &value);
+    screen.attroff(black);
+    screen.attron(color);
+    screen.mvprintw(y, x + value.len() as i32, &suit);
+    screen.attroff(color);
+    let spacing = width - value.len() as i32 - 1;
+    addstr_repeat(screen, " ", spacing, black);
+    for i in 1..height - 1 {
+        screen.mv(y + i, x);
+        addstr_repeat(screen, " ", width, black);
+    }
+    if height > 1 {
+        screen.mv(y + height - 1, x);
+        addstr_repeat(screen, " ", spacing, black);
+        screen.attron(black);
+        screen.addstr(&value);
+        screen.attroff(black);
+        screen.attron(color);
+        screen.addstr(&suit);
+        screen.attroff(color);
+    }
+    if lucky {
+        // Single-line cards have no room in the middle, so the star goes
+        // at the end of the line
+        let (star_x, star_y) = if height > 1 {
+            (x + width/2, y + height/2)
+        } else {
+            (x + width - 1, y)
+        };
+        let star = color_pair(LUCKY_COLOR) | BOLD;
+        screen.attron(star);
+        screen.mvprintw(star_y, star_x, "\u{2605}");
+        screen.attroff(star);
     }
-    ncurses::mv(y + CARD_HEIGHT - 1, x);
-    addstr_repeat(" ", spacing, black);
-    ncurses::attron(black);
-    ncurses::addstr(&value);
-    ncurses::attroff(black);
-    ncurses::attron(color);
-    ncurses::addstr(&suit);
-    ncurses::attroff(color);
 }
 
-/// Draw empty slot for a card
-fn draw_empty<T: Scorer>(game: &Game<T>, position: Position) {
-    let color = ncurses::COLOR_PAIR(CARD_COLOR_EMPTY);
-    let (x, y) = card_location(position);
-    ncurses::attron(color);
-    ncurses::mvprintw(y, x, "┌");
-    addstr_repeat("─", CARD_WIDTH - 2, color);
-    ncurses::mvprintw(y, x + CARD_WIDTH - 1, "┐");
-    ncurses::attroff(color);
-    let gap_height = CARD_HEIGHT - 1;
+/// Draw the edge of the cards beneath the top card of a stack, shaded more
+/// heavily the more cards remain
+fn draw_shadow(screen: &mut dyn Screen, layout: &Layout, x: i32, y: i32, count: usize) {
+    let shade = match count {
+        0 | 1 => " ",
+        2 | 3 => "\u{2591}",
+        4 | 5 => "\u{2592}",
+        6 | 7 => "\u{2593}",
+        _ => "\u{2588}",
+    };
+    let color = color_pair(CARD_COLOR_EMPTY);
+    for i in 1..layout.card_height + layout.shadow_size {
+        screen.mv(y + i, x + layout.card_width);
+        addstr_repeat(screen, shade, layout.shadow_size, color);
+    }
+    for i in 0..layout.shadow_size {
+        screen.mv(y + layout.card_height + i, x + 1);
+        addstr_repeat(screen, shade, layout.card_width - 1, color);
+    }
+}
+
+/// Print the number of cards in a stack beneath it, abbreviated when the
+/// stack is too narrow to fit the full count
+fn draw_badge(screen: &mut dyn Screen, layout: &Layout, x: i32, y: i32, count: usize) {
+    if layout.badge_height == 0 {
+        return
+    }
+    let width = layout.stack_width();
+    let mut badge = match count {
+        0 => "".to_string(),
+        1 => "1 card".to_string(),
+        _ => format!("{} cards", count),
+    };
+    if badge.len() as i32 > width {
+        badge = if count == 0 { "".to_string() } else { format!("{}", count) };
+    }
+    let color = color_pair(CURSOR_INFO_COLOR);
+    let lede = cmp::max(0, (width - badge.len() as i32)/2);
+    screen.mv(y + layout.card_height + layout.shadow_size + 1, x);
+    addstr_repeat(screen, " ", lede, color);
+    screen.attron(color);
+    screen.addstr(&badge);
+    screen.attroff(color);
+    addstr_repeat(screen, " ", width - lede - badge.len() as i32, color);
+}
+
+/// Draw empty slot for a card, showing the bonus for clearing the stack,
+/// highlighted when flashing
+fn draw_empty<T: Scorer>(screen: &mut dyn Screen, layout: &Layout, game: &Game<T>, position: Position, flash: bool) {
+    let color = if flash {
+        color_pair(GAME_INFO_COLOR) | REVERSE | BOLD
+    } else {
+        color_pair(CARD_COLOR_EMPTY)
+    };
+    let (x, y) = layout.card_location(position);
+    let (width, height) = (layout.card_width, layout.card_height);
+    let bonus = format!("+{}", game.scorer.bonus(position));
+    if height < 3 {
+        let available_width = cmp::max(0, width - bonus.len() as i32);
+        let lede = available_width/2;
+        screen.mv(y, x);
+        addstr_repeat(screen, "\u{00b7}", lede, color);
+        addstr_color(screen, &bonus, color);
+        addstr_repeat(screen, "\u{00b7}", available_width - lede, color);
+        return
+    }
+    screen.attron(color);
+    screen.mvprintw(y, x, "┌");
+    addstr_repeat(screen, "─", width - 2, color);
+    screen.mvprintw(y, x + width - 1, "┐");
+    screen.attroff(color);
+    let gap_height = height - 1;
     let bonus_height = gap_height/2;
     for i in 1..gap_height {
-        ncurses::attron(color);
-        ncurses::mvprintw(y + i, x , "│");
+        screen.attron(color);
+        screen.mvprintw(y + i, x , "│");
         if i == bonus_height {
-            let bonus = format!("+{}", game.scorer.bonus(position));
-            let available_width = cmp::max(0, CARD_WIDTH - 2 - bonus.len() as i32);
+            let available_width = cmp::max(0, width - 2 - bonus.len() as i32);
             let lede = available_width/2;
-            addstr_repeat(" ", lede, color);
-            ncurses::addstr(&bonus);
-            addstr_repeat(" ", available_width - lede, color);
+            addstr_repeat(screen, " ", lede, color);
+            screen.addstr(&bonus);
+            addstr_repeat(screen, " ", available_width - lede, color);
         } else {
-            addstr_repeat(" ", CARD_WIDTH - 2, color);
+            addstr_repeat(screen, " ", width - 2, color);
         }
-        ncurses::attroff(color);
-        ncurses::attron(color);
-        ncurses::mvprintw(y + i, x + CARD_WIDTH - 1, "│");
-        ncurses::attroff(color);
+        screen.attroff(color);
+        screen.attron(color);
+        screen.mvprintw(y + i, x + width - 1, "│");
+        screen.attroff(color);
     }
-    ncurses::attron(color);
-    ncurses::mvprintw(y + CARD_HEIGHT - 1, x, "└");
-    addstr_repeat("─", CARD_WIDTH - 2, color);
-    ncurses::mvprintw(y + CARD_HEIGHT - 1, x + CARD_WIDTH - 1, "┘");
-    ncurses::attroff(color);
+    screen.attron(color);
+    screen.mvprintw(y + height - 1, x, "└");
+    addstr_repeat(screen, "─", width - 2, color);
+    screen.mvprintw(y + height - 1, x + width - 1, "┘");
+    screen.attroff(color);
 }
 
 /// Print a string repeatedly to fill a length
-fn addstr_repeat(content: &str, len: i32, color: ncurses::chtype) {
-    ncurses::attron(color);
+fn addstr_repeat(screen: &mut dyn Screen, content: &str, len: i32, color: Attributes) {
+    screen.attron(color);
     for _ in 0..len {
-       ncurses::addstr(content);
+       screen.addstr(content);
     }
-    ncurses::attroff(color);
+    screen.attroff(color);
 }
 
-/// Print or clear the border around a card
-fn toggle_highlight_card(x: i32, y: i32, on: bool) {
-    let color = ncurses::COLOR_PAIR(SELECTED_COLOR);
-    ncurses::attron(color);
-    ncurses::mvprintw(y - 1, x - 1, if on {"┌"} else {" "});
-    ncurses::attroff(color);
-    ncurses::mv(y - 1, x);
-    addstr_repeat(if on {"─"} else {" "}, CARD_WIDTH, color);
-    ncurses::mv(y + CARD_HEIGHT, x);
-    addstr_repeat(if on {"─"} else {" "}, CARD_WIDTH, color);
-    ncurses::attron(color);
-    ncurses::mvprintw(y - 1, x + CARD_WIDTH, if on {"┐"} else {" "});
-    for i in 0..CARD_HEIGHT {
-        ncurses::mvprintw(y + i, x - 1, if on {"│"} else {" "});
-        ncurses::mvprintw(y + i, x + CARD_WIDTH, if on {"│"} else {" "});
+/// Print or clear the border around a card and its shadow
+fn toggle_highlight_card(screen: &mut dyn Screen, layout: &Layout, x: i32, y: i32, on: bool) {
+    let width = layout.card_width + layout.shadow_size;
+    let height = layout.card_height + layout.shadow_size;
+    let color = color_pair(SELECTED_COLOR);
+    screen.attron(color);
+    screen.mvprintw(y - 1, x - 1, if on {"┌"} else {" "});
+    screen.attroff(color);
+    screen.mv(y - 1, x);
+    addstr_repeat(screen, if on {"─"} else {" "}, width, color);
+    screen.mv(y + height, x);
+    addstr_repeat(screen, if on {"─"} else {" "}, width, color);
+    screen.attron(color);
+    screen.mvprintw(y - 1, x + width, if on {"┐"} else {" "});
+    for i in 0..height {
+        screen.mvprintw(y + i, x - 1, if on {"│"} else {" "});
+        screen.mvprintw(y + i, x + width, if on {"│"} else {" "});
     }
-    ncurses::mvprintw(y + CARD_HEIGHT, x - 1, if on {"└"} else {" "});
-    ncurses::mvprintw(y + CARD_HEIGHT, x + CARD_WIDTH, if on {"┘"} else {" "});
-    ncurses::attroff(color);
+    screen.mvprintw(y + height, x - 1, if on {"└"} else {" "});
+    screen.mvprintw(y + height, x + width, if on {"┘"} else {" "});
+    screen.attroff(color);
 }
 
 /// Printed representation of a card's suit
-fn layout_suit(card: cards::card::Card) -> (ncurses::chtype, String) {
-    let black = ncurses::COLOR_PAIR(CARD_COLOR_BLACK);
-    let red = ncurses::COLOR_PAIR(CARD_COLOR_RED);
-    return match card.suit {
-        cards::card::Suit::Diamonds => (red, "\u{2666}".to_string()),
-        cards::card::Suit::Clubs => (black, "\u{2663}".to_string()),
-        cards::card::Suit::Spades => (black, "\u{2660}".to_string()),
-        cards::card::Suit::Hearts => (red, "\u{2665}".to_string()),
-    }
-}
-
-/// Printed representation of a card's value
-fn layout_value(card: cards::card::Card) -> String {
-    return match card.value {
-        cards::card::Value::Ace => "A",
-        cards::card::Value::Two => "2",
-        cards::card::Value::Three => "3",
-        cards::card::Value::Four => "4",
-        cards::card::Value::Five => "5",
-        cards::card::Value::Six => "6",
-        cards::card::Value::Seven => "7",
-        cards::card::Value::Eight => "8",
-        cards::card::Value::Nine => "9",
-        cards::card::Value::Ten => "10",
-        cards::card::Value::Jack => "J",
-        cards::card::Value::Queen => "Q",
-        cards::card::Value::King => "K",
-    }.to_string()
-}
-
-/// Location (x, y) for a card position
-fn card_location(position: Position) -> (i32, i32) {
-    let left = BOARD_MARGIN + CARD_MARGIN;
-    let center = BOARD_MARGIN + CARD_MARGIN*2 + CARD_WIDTH;
-    let right = BOARD_MARGIN + CARD_MARGIN*3 + CARD_WIDTH*2;
-    let top = BOARD_MARGIN + CARD_MARGIN;
-    let middle = BOARD_MARGIN + CARD_MARGIN*2 + CARD_HEIGHT;
-    let bottom = BOARD_MARGIN + CARD_MARGIN*3 + CARD_HEIGHT*2;
-    match (position.x, position.y) {
-        (HPosition::Left, VPosition::Top) => (left, top),
-        (HPosition::Left, VPosition::Middle) => (left, middle),
-        (HPosition::Left, VPosition::Bottom) => (left, bottom),
-        (HPosition::Center, VPosition::Top) => (center, top),
-        (HPosition::Center, VPosition::Middle) => (center, middle),
-        (HPosition::Center, VPosition::Bottom) => (center, bottom),
-        (HPosition::Right, VPosition::Top) => (right, top),
-        (HPosition::Right, VPosition::Middle) => (right, middle),
-        (HPosition::Right, VPosition::Bottom) => (right, bottom),
-    }
+fn layout_suit(card: cards::card::Card) -> (Attributes, String) {
+    let black = color_pair(CARD_COLOR_BLACK);
+    let red = color_pair(CARD_COLOR_RED);
+    let color = match card.suit {
+        cards::card::Suit::Diamonds | cards::card::Suit::Hearts => red,
+        cards::card::Suit::Clubs | cards::card::Suit::Spades => black,
+    };
+    return (color, format_suit(card.suit, CardStyle::Unicode))
 }
@@ -0,0 +1,347 @@
+use std::io::Write;
+
+/// Attributes of printed characters, laid out as in ncurses: the color pair
+/// in the second byte and styles above it
+pub type Attributes = u32;
+
+pub const REVERSE: Attributes = 1 << 18;
+pub const DIM: Attributes = 1 << 20;
+pub const BOLD: Attributes = 1 << 21;
+
+const PAIR_MASK: Attributes = 0xff00;
+const PAIR_SHIFT: Attributes = 8;
+
+pub const COLOR_BLACK: i16 = 0;
+pub const COLOR_RED: i16 = 1;
+pub const COLOR_GREEN: i16 = 2;
+pub const COLOR_YELLOW: i16 = 3;
+pub const COLOR_BLUE: i16 = 4;
+pub const COLOR_MAGENTA: i16 = 5;
+pub const COLOR_CYAN: i16 = 6;
+pub const COLOR_WHITE: i16 = 7;
+
+/// Key codes, matching those returned by ncurses
+pub const ERR: i32 = -1;
+pub const KEY_DOWN: i32 = 258;
+pub const KEY_UP: i32 = 259;
+pub const KEY_LEFT: i32 = 260;
+pub const KEY_RIGHT: i32 = 261;
+pub const KEY_ENTER: i32 = 343;
+pub const KEY_RESIZE: i32 = 410;
+/// Code for keys which have no equivalent in the game
+pub const KEY_OTHER: i32 = 511;
+/// Code returned once input is closed, such as when a remote player
+/// disconnects
+pub const KEY_CLOSED: i32 = -2;
+
+/// Attributes printing characters in a color pair
+pub fn color_pair(pair: i16) -> Attributes {
+    return ((pair as Attributes) << PAIR_SHIFT) & PAIR_MASK
+}
+
+/// Color pair used by a set of attributes, or 0 if none is set
+pub fn pair_number(attributes: Attributes) -> i16 {
+    return ((attributes & PAIR_MASK) >> PAIR_SHIFT) as i16
+}
+
+/// A terminal which is drawn on by the renderer and read keys from, using
+/// the same model as ncurses: characters are printed at a cursor using the
+/// current attributes, and shown once the screen is refreshed
+pub trait Screen {
+
+    /// Number of lines in the terminal
+    fn lines(&self) -> i32;
+
+    /// Number of columns in the terminal
+    fn cols(&self) -> i32;
+
+    /// Set the foreground and background colors of a color pair
+    fn init_pair(&mut self, pair: i16, foreground: i16, background: i16);
+
+    /// Move the cursor to a location (y, x), unless it is off the screen
+    fn mv(&mut self, y: i32, x: i32);
+
+    /// Print a string at the cursor, wrapping onto the next line at the
+    /// right edge of the screen
+    fn addstr(&mut self, content: &str);
+
+    /// Print a string at a location (y, x), unless it is off the screen
+    fn mvprintw(&mut self, y: i32, x: i32, content: &str) {
+        if y >= 0 && y < self.lines() && x >= 0 && x < self.cols() {
+            self.mv(y, x);
+            self.addstr(content);
+        }
+    }
+
+    /// Print later strings using a set of attributes, replacing the color
+    /// pair if one is given
+    fn attron(&mut self, attributes: Attributes);
+
+    /// Stop printing later strings using a set of attributes
+    fn attroff(&mut self, attributes: Attributes);
+
+    /// Set the colors of blank cells and cells printed without a color pair
+    fn bkgd(&mut self, attributes: Attributes);
+
+    /// Blank the rest of the line from the cursor
+    fn clrtoeol(&mut self);
+
+    /// Blank the screen
+    fn erase(&mut self);
+
+    /// Blank the screen and redraw all of it on the next refresh
+    fn clear(&mut self);
+
+    /// Show everything printed since the last refresh
+    fn refresh(&mut self);
+
+    /// Wait at most a number of milliseconds for a key in `getch`, or wait
+    /// indefinitely if negative
+    fn timeout(&mut self, milliseconds: i32);
+
+    /// Refresh the screen and wait for a key, returning its code,
+    /// `KEY_RESIZE` if the terminal was resized, `ERR` if no key was pressed
+    /// before the timeout or `KEY_CLOSED` if input was closed
+    fn getch(&mut self) -> i32;
+
+    /// Stop drawing and restore the terminal
+    fn end(&mut self);
+}
+
+/// Source of keys for a screen written as ANSI escape sequences
+pub trait Input {
+
+    /// Wait at most a number of milliseconds for a key, or indefinitely if
+    /// negative, returning a code as described in `Screen::getch`
+    fn read_key(&mut self, milliseconds: i32) -> i32;
+
+    /// Size (lines, columns) of the terminal
+    fn size(&self) -> (i32, i32);
+
+    /// Stop reading keys and restore the terminal
+    fn close(&mut self);
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+struct Cell {
+    content: char,
+    attributes: Attributes,
+}
+
+const BLANK: Cell = Cell { content: ' ', attributes: 0 };
+
+/// A screen written to any output as ANSI escape sequences, such as to
+/// standard output or a socket. Characters are drawn into a buffer and only
+/// the cells which changed since the last refresh are written.
+pub struct AnsiScreen<W: Write, I: Input> {
+    output: W,
+    input: I,
+    lines: i32,
+    cols: i32,
+    cells: Vec<Cell>,
+    written: Vec<Cell>,
+    /// Location (y, x) where the next character is printed
+    cursor: (i32, i32),
+    attributes: Attributes,
+    background: Attributes,
+    /// Foreground and background colors of each color pair
+    pairs: Vec<(i16, i16)>,
+    timeout: i32,
+    /// Whether the terminal must be cleared and every cell rewritten
+    cleared: bool,
+}
+
+impl<W: Write, I: Input> AnsiScreen<W, I> {
+
+    /// Start drawing to an output in the alternate screen with the cursor
+    /// hidden, reading keys from an input
+    pub fn new(mut output: W, input: I) -> AnsiScreen<W, I> {
+        let (lines, cols) = input.size();
+        output.write_all(b"\x1b[?1049h\x1b[?25l").ok();
+        let mut screen = AnsiScreen {
+            output: output,
+            input: input,
+            lines: 0,
+            cols: 0,
+            cells: vec![],
+            written: vec![],
+            cursor: (0, 0),
+            attributes: 0,
+            background: 0,
+            pairs: vec![(-1, -1); 256],
+            timeout: -1,
+            cleared: true,
+        };
+        screen.resize(lines, cols);
+        return screen
+    }
+
+    /// Change the size of the screen, discarding its contents
+    fn resize(&mut self, lines: i32, cols: i32) {
+        let size = (lines*cols) as usize;
+        self.lines = lines;
+        self.cols = cols;
+        self.cells = vec![BLANK; size];
+        self.written = vec![BLANK; size];
+        self.cursor = (0, 0);
+        self.cleared = true;
+    }
+
+    fn contains(&self, y: i32, x: i32) -> bool {
+        return y >= 0 && y < self.lines && x >= 0 && x < self.cols
+    }
+
+    fn blank_from(&mut self, y: i32, x: i32) {
+        if !self.contains(y, x) {
+            return
+        }
+        let start = (y*self.cols + x) as usize;
+        let end = ((y + 1)*self.cols) as usize;
+        for cell in self.cells[start..end].iter_mut() {
+            *cell = BLANK;
+        }
+    }
+
+    /// Escape sequence setting the colors and styles of a cell
+    fn style(&self, attributes: Attributes) -> String {
+        let mut pair = pair_number(attributes);
+        if pair == 0 {
+            pair = pair_number(self.background);
+        }
+        let (foreground, background) = self.pairs[pair as usize];
+        let mut codes = vec!["0".to_string()];
+        if attributes & BOLD != 0 {
+            codes.push("1".to_string());
+        }
+        if attributes & DIM != 0 {
+            codes.push("2".to_string());
+        }
+        if attributes & REVERSE != 0 {
+            codes.push("7".to_string());
+        }
+        codes.push(if foreground < 0 { "39".to_string() } else { format!("3{}", foreground) });
+        codes.push(if background < 0 { "49".to_string() } else { format!("4{}", background) });
+        return format!("\x1b[{}m", codes.join(";"))
+    }
+}
+
+impl<W: Write, I: Input> Screen for AnsiScreen<W, I> {
+
+    fn lines(&self) -> i32 {
+        return self.lines
+    }
+
+    fn cols(&self) -> i32 {
+        return self.cols
+    }
+
+    fn init_pair(&mut self, pair: i16, foreground: i16, background: i16) {
+        self.pairs[pair as usize] = (foreground, background);
+    }
+
+    fn mv(&mut self, y: i32, x: i32) {
+        if self.contains(y, x) {
+            self.cursor = (y, x);
+        }
+    }
+
+    fn addstr(&mut self, content: &str) {
+        for c in content.chars() {
+            let (y, x) = self.cursor;
+            if !self.contains(y, x) {
+                return
+            }
+            let index = (y*self.cols + x) as usize;
+            self.cells[index] = Cell { content: c, attributes: self.attributes };
+            self.cursor = if x + 1 < self.cols { (y, x + 1) } else { (y + 1, 0) };
+        }
+    }
+
+    fn attron(&mut self, attributes: Attributes) {
+        if attributes & PAIR_MASK != 0 {
+            self.attributes &= !PAIR_MASK;
+        }
+        self.attributes |= attributes;
+    }
+
+    fn attroff(&mut self, attributes: Attributes) {
+        if attributes & PAIR_MASK != 0 {
+            self.attributes &= !PAIR_MASK;
+        }
+        self.attributes &= !(attributes & !PAIR_MASK);
+    }
+
+    fn bkgd(&mut self, attributes: Attributes) {
+        self.background = attributes;
+    }
+
+    fn clrtoeol(&mut self) {
+        let (y, x) = self.cursor;
+        self.blank_from(y, x);
+    }
+
+    fn erase(&mut self) {
+        for line in 0..self.lines {
+            self.blank_from(line, 0);
+        }
+        self.cursor = (0, 0);
+    }
+
+    fn clear(&mut self) {
+        self.erase();
+        self.cleared = true;
+    }
+
+    fn refresh(&mut self) {
+        let mut content = String::new();
+        if self.cleared {
+            content.push_str("\x1b[0m\x1b[2J");
+        }
+        let mut last_attributes = None;
+        let mut next_index = None;
+        for index in 0..self.cells.len() {
+            let cell = self.cells[index];
+            if !self.cleared && cell == self.written[index] {
+                continue
+            }
+            if next_index != Some(index) {
+                let (y, x) = (index as i32 / self.cols, index as i32 % self.cols);
+                content.push_str(&format!("\x1b[{};{}H", y + 1, x + 1));
+            }
+            if last_attributes != Some(cell.attributes) {
+                content.push_str(&self.style(cell.attributes));
+                last_attributes = Some(cell.attributes);
+            }
+            content.push(cell.content);
+            self.written[index] = cell;
+            next_index = Some(index + 1);
+        }
+        self.cleared = false;
+        if content.is_empty() {
+            return
+        }
+        content.push_str("\x1b[0m");
+        self.output.write_all(content.as_bytes()).ok();
+        self.output.flush().ok();
+    }
+
+    fn timeout(&mut self, milliseconds: i32) {
+        self.timeout = milliseconds;
+    }
+
+    fn getch(&mut self) -> i32 {
+        self.refresh();
+        let key = self.input.read_key(self.timeout);
+        if key == KEY_RESIZE {
+            let (lines, cols) = self.input.size();
+            self.resize(lines, cols);
+        }
+        return key
+    }
+
+    fn end(&mut self) {
+        self.output.write_all(b"\x1b[0m\x1b[?25h\x1b[?1049l").ok();
+        self.output.flush().ok();
+        self.input.close();
+    }
+}
@@ -0,0 +1,68 @@
+extern crate crossterm;
+
+use self::crossterm::event::{Event,KeyCode,KeyEvent,KeyEventKind,KeyModifiers};
+use screen::{AnsiScreen,Input,Screen,ERR,KEY_CLOSED,KEY_DOWN,KEY_LEFT,KEY_OTHER,KEY_RESIZE,KEY_RIGHT,KEY_UP};
+use std::io::stdout;
+use std::time::{Duration,Instant};
+
+/// Keys read from the terminal running the game using crossterm
+struct CrosstermInput;
+
+/// Start drawing in the terminal, reading keys without echoing them and
+/// hiding the cursor
+pub fn open() -> Box<dyn Screen> {
+    self::crossterm::terminal::enable_raw_mode().ok();
+    return Box::new(AnsiScreen::new(stdout(), CrosstermInput))
+}
+
+impl Input for CrosstermInput {
+
+    fn read_key(&mut self, milliseconds: i32) -> i32 {
+        let deadline = if milliseconds < 0 {
+            None
+        } else {
+            Some(Instant::now() + Duration::from_millis(milliseconds as u64))
+        };
+        loop {
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if !self::crossterm::event::poll(remaining).unwrap_or(false) {
+                    return ERR
+                }
+            }
+            match self::crossterm::event::read() {
+                Ok(Event::Key(key)) if key.kind != KeyEventKind::Release => return key_code(key),
+                Ok(Event::Resize(_, _)) => return KEY_RESIZE,
+                Ok(_) => {},
+                Err(_) => return ERR,
+            }
+        }
+    }
+
+    fn size(&self) -> (i32, i32) {
+        let (cols, lines) = self::crossterm::terminal::size().unwrap_or((80, 24));
+        return (lines as i32, cols as i32)
+    }
+
+    fn close(&mut self) {
+        self::crossterm::terminal::disable_raw_mode().ok();
+    }
+}
+
+/// Code ncurses returns for a key. Raw mode stops the terminal from
+/// interrupting the program on Ctrl-C, so it closes input instead.
+fn key_code(key: KeyEvent) -> i32 {
+    return match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => KEY_CLOSED,
+        KeyCode::Char(c) => c as i32,
+        KeyCode::Left => KEY_LEFT,
+        KeyCode::Right => KEY_RIGHT,
+        KeyCode::Up => KEY_UP,
+        KeyCode::Down => KEY_DOWN,
+        KeyCode::Enter => 10,
+        KeyCode::Tab => 9,
+        KeyCode::Backspace => 127,
+        KeyCode::Esc => 27,
+        _ => KEY_OTHER,
+    }
+}
@@ -0,0 +1,102 @@
+extern crate ncurses;
+
+use screen::{Attributes,Screen,BOLD,DIM,REVERSE,pair_number};
+
+/// The terminal running the game, drawn with ncurses
+struct Curses;
+
+/// Start drawing in the terminal, reading keys without echoing them and
+/// hiding the cursor
+pub fn open() -> Box<dyn Screen> {
+    ncurses::setlocale(ncurses::LcCategory::ctype, "");
+    ncurses::initscr();
+    ncurses::noecho();
+    ncurses::start_color();
+    ncurses::keypad(ncurses::stdscr(), true);
+    // Read escape as a key press without waiting for a longer sequence
+    ncurses::set_escdelay(25);
+    ncurses::curs_set(ncurses::CURSOR_VISIBILITY::CURSOR_INVISIBLE);
+    return Box::new(Curses)
+}
+
+/// Attributes as ncurses lays them out
+fn curses_attributes(attributes: Attributes) -> ncurses::attr_t {
+    let mut result = ncurses::COLOR_PAIR(pair_number(attributes));
+    if attributes & BOLD != 0 {
+        result |= ncurses::A_BOLD();
+    }
+    if attributes & DIM != 0 {
+        result |= ncurses::A_DIM();
+    }
+    if attributes & REVERSE != 0 {
+        result |= ncurses::A_REVERSE();
+    }
+    return result
+}
+
+impl Screen for Curses {
+
+    fn lines(&self) -> i32 {
+        return ncurses::LINES()
+    }
+
+    fn cols(&self) -> i32 {
+        return ncurses::COLS()
+    }
+
+    fn init_pair(&mut self, pair: i16, foreground: i16, background: i16) {
+        ncurses::init_pair(pair, foreground, background);
+    }
+
+    fn mv(&mut self, y: i32, x: i32) {
+        ncurses::mv(y, x);
+    }
+
+    fn addstr(&mut self, content: &str) {
+        ncurses::addstr(content);
+    }
+
+    fn mvprintw(&mut self, y: i32, x: i32, content: &str) {
+        ncurses::mvprintw(y, x, content);
+    }
+
+    fn attron(&mut self, attributes: Attributes) {
+        ncurses::attron(curses_attributes(attributes));
+    }
+
+    fn attroff(&mut self, attributes: Attributes) {
+        ncurses::attroff(curses_attributes(attributes));
+    }
+
+    fn bkgd(&mut self, attributes: Attributes) {
+        ncurses::bkgd(curses_attributes(attributes));
+    }
+
+    fn clrtoeol(&mut self) {
+        ncurses::clrtoeol();
+    }
+
+    fn erase(&mut self) {
+        ncurses::erase();
+    }
+
+    fn clear(&mut self) {
+        ncurses::clear();
+    }
+
+    fn refresh(&mut self) {
+        ncurses::refresh();
+    }
+
+    fn timeout(&mut self, milliseconds: i32) {
+        ncurses::timeout(milliseconds);
+    }
+
+    fn getch(&mut self) -> i32 {
+        return ncurses::getch()
+    }
+
+    fn end(&mut self) {
+        ncurses::endwin();
+    }
+}
@@ -0,0 +1,103 @@
+extern crate cards;
+extern crate libthyme;
+extern crate ui;
+
+use cards::card::{Card,Suit,Value};
+use libthyme::board::{Board,Position,HPosition,VPosition};
+use libthyme::game::MoveType;
+use libthyme::score::Play;
+use ui::UI;
+use ui::animation::{Animation,Effect,FRAME_DURATION};
+use std::time::Duration;
+
+#[test]
+fn play_card_flips_next_card_after_slide() {
+    let card = Card { value: Value::Five, suit: Suit::Clubs };
+    let position = Position { x: HPosition::Left, y: VPosition::Top };
+    let animations = Animation::play_card(card, position, false);
+    assert_eq!(animations[0].effect, Effect::SlideOff(card));
+    assert_eq!(animations[1].effect, Effect::FlipUp);
+    assert_eq!(animations[1].delay, animations[0].frame_count());
+}
+
+#[test]
+fn play_card_flashes_bonus_when_cleared() {
+    let card = Card { value: Value::Five, suit: Suit::Clubs };
+    let position = Position { x: HPosition::Left, y: VPosition::Top };
+    let animations = Animation::play_card(card, position, true);
+    assert_eq!(animations[1].effect, Effect::FlashBonus);
+}
+
+#[test]
+fn progress_waits_for_delay() {
+    let card = Card { value: Value::Five, suit: Suit::Clubs };
+    let position = Position { x: HPosition::Left, y: VPosition::Top };
+    let mut flip = Animation::play_card(card, position, false)[1];
+    assert!(!flip.is_started());
+    assert_eq!(flip.progress(), 0.0);
+    flip.frame = flip.delay + flip.frame_count();
+    assert!(flip.is_finished());
+    assert_eq!(flip.progress(), 1.0);
+}
+
+#[test]
+fn advance_animations_removes_finished() {
+    let mut ui = animated_ui(true);
+    assert!(ui.is_animating());
+    let start = ui.animation_clock.unwrap();
+    ui.advance_animations_to(start + Duration::from_secs(10));
+    assert!(!ui.is_animating());
+    assert!(ui.animation_clock.is_none());
+}
+
+#[test]
+fn advance_animations_counts_elapsed_frames() {
+    let mut ui = animated_ui(true);
+    let start = ui.animation_clock.unwrap();
+    let frame = Duration::from_millis(FRAME_DURATION as u64);
+    ui.advance_animations_to(start + frame/2);
+    assert_eq!(ui.animations[0].frame, 0);
+    ui.advance_animations_to(start + frame*2 + frame/2);
+    assert_eq!(ui.animations[0].frame, 2);
+    // Advancing again at the same moment, such as for a repeated key,
+    // leaves the frame as it is
+    ui.advance_animations_to(start + frame*2 + frame/2);
+    assert_eq!(ui.animations[0].frame, 2);
+    ui.advance_animations_to(start + frame*3);
+    assert_eq!(ui.animations[0].frame, 3);
+}
+
+#[test]
+fn hold_animations_discards_elapsed_time() {
+    let mut ui = animated_ui(true);
+    let start = ui.animation_clock.unwrap();
+    ui.hold_animations();
+    assert!(ui.animation_clock.unwrap() >= start);
+    let mut still = UI::new();
+    still.hold_animations();
+    assert!(still.animation_clock.is_none());
+}
+
+#[test]
+fn animate_play_does_nothing_when_disabled() {
+    let ui = animated_ui(false);
+    assert!(!ui.is_animating());
+}
+
+fn animated_ui(enabled: bool) -> UI {
+    let board = Board::new();
+    let mut ui = UI::new();
+    ui.animations_enabled = enabled;
+    ui.selection = vec![
+        Position { x: HPosition::Left, y: VPosition::Top },
+        Position { x: HPosition::Center, y: VPosition::Middle }];
+    let play = Play {
+        cards: vec![
+            Card { value: Value::Five, suit: Suit::Clubs },
+            Card { value: Value::Five, suit: Suit::Hearts }],
+        cleared_positions: vec![],
+        hand: MoveType::Pair,
+    };
+    ui.animate_play(&board, &play);
+    return ui
+}
@@ -0,0 +1,44 @@
+extern crate ui;
+
+use ui::Action;
+use ui::dialog::{Answer,Dialog};
+
+#[test]
+fn confirm_answers_yes_or_no() {
+    let dialog = Dialog::confirm("Quit?", "The current game will be lost");
+    assert_eq!(dialog.answer(&Action::Confirm), Some(Answer::Yes));
+    assert_eq!(dialog.answer(&Action::Play), Some(Answer::Yes));
+    assert_eq!(dialog.answer(&Action::Cancel), Some(Answer::No));
+}
+
+#[test]
+fn confirm_ignores_other_keys() {
+    let dialog = Dialog::confirm("Quit?", "The current game will be lost");
+    assert_eq!(dialog.answer(&Action::Quit), None);
+    assert_eq!(dialog.answer(&Action::CursorLeft), None);
+    assert_eq!(dialog.answer(&Action::Idle), None);
+}
+
+#[test]
+fn message_is_dismissed_by_any_key() {
+    let dialog = Dialog::message("Paused", "The board is hidden while paused");
+    assert_eq!(dialog.answer(&Action::Unknown), Some(Answer::Yes));
+    assert_eq!(dialog.answer(&Action::Cancel), Some(Answer::Yes));
+    assert_eq!(dialog.answer(&Action::Idle), None);
+    assert_eq!(dialog.answer(&Action::Resize), None);
+}
+
+#[test]
+fn notice_is_not_closed_by_keys() {
+    let dialog = Dialog::notice("Waiting for Players", "1 of 2 players have joined");
+    assert_eq!(dialog.answer(&Action::Confirm), None);
+    assert_eq!(dialog.answer(&Action::Quit), None);
+    assert_eq!(dialog.prompt(), "");
+}
+
+#[test]
+fn hiding_board() {
+    let dialog = Dialog::message("Paused", "The board is hidden while paused");
+    assert!(!dialog.hides_board);
+    assert!(dialog.hiding_board().hides_board);
+}
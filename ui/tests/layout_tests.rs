@@ -0,0 +1,56 @@
+extern crate ui;
+extern crate libthyme;
+
+use ui::layout::{Layout,LayoutSize};
+use libthyme::board::{Position,HPosition,VPosition};
+
+#[test]
+fn for_screen_chooses_largest_fitting_layout() {
+    assert_eq!(Layout::for_screen(60, 140).unwrap().size, LayoutSize::Large);
+    assert_eq!(Layout::for_screen(40, 80).unwrap().size, LayoutSize::Normal);
+    assert_eq!(Layout::for_screen(24, 80).unwrap().size, LayoutSize::Compact);
+    assert_eq!(Layout::for_screen(16, 30).unwrap().size, LayoutSize::Mini);
+}
+
+#[test]
+fn for_screen_rejects_tiny_screen() {
+    assert!(Layout::for_screen(10, 20).is_none());
+}
+
+#[test]
+fn for_screen_fits_board_on_screen() {
+    for &(lines, cols) in [(60, 140), (40, 80), (24, 80), (16, 30)].iter() {
+        let layout = Layout::for_screen(lines, cols).unwrap();
+        let (x, y) = layout.origin;
+        assert!(x + layout.board_width() <= cols);
+        assert!(y + layout.board_height() <= lines);
+    }
+}
+
+#[test]
+fn for_screen_centers_board() {
+    let layout = Layout::for_screen(100, 200).unwrap();
+    let (x, y) = layout.origin;
+    let (tracker_x, _) = layout.tracker.unwrap();
+    let right_space = 200 - (tracker_x + ui::layout::TRACKER_WIDTH);
+    assert!((x - right_space).abs() <= 1);
+    assert!(y > ui::layout::TITLE_HEIGHT);
+}
+
+#[test]
+fn for_screen_omits_tracker_on_narrow_screen() {
+    assert!(Layout::for_screen(40, 80).unwrap().tracker.is_some());
+    assert!(Layout::for_screen(40, 60).unwrap().tracker.is_none());
+}
+
+#[test]
+fn card_location_spaces_stacks() {
+    let layout = Layout::new(LayoutSize::Normal);
+    let top_left = Position { x: HPosition::Left, y: VPosition::Top };
+    let middle_center = Position { x: HPosition::Center, y: VPosition::Middle };
+    let (left, top) = layout.card_location(top_left);
+    let (center, middle) = layout.card_location(middle_center);
+    assert_eq!((left, top), (4, 4));
+    assert_eq!(center - left, layout.stack_width() + layout.card_margin);
+    assert_eq!(middle - top, layout.stack_height() + layout.card_margin);
+}
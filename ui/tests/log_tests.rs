@@ -0,0 +1,74 @@
+extern crate ui;
+
+use ui::UI;
+use ui::log::MessageLog;
+
+#[test]
+fn push_drops_oldest_message_when_full() {
+    let mut log = MessageLog::new(3);
+    for message in ["one", "two", "three", "four"].iter() {
+        log.push(message);
+    }
+    assert_eq!(log.len(), 3);
+    assert_eq!(log.visible(10), vec!["two", "three", "four"]);
+}
+
+#[test]
+fn log_without_capacity_keeps_newest_message() {
+    let mut log = MessageLog::new(0);
+    log.push("one");
+    log.push("two");
+    assert_eq!(log.len(), 1);
+    assert_eq!(log.visible(10), vec!["two"]);
+}
+
+#[test]
+fn push_skips_empty_messages() {
+    let mut log = MessageLog::new(3);
+    log.push("");
+    assert!(log.is_empty());
+}
+
+#[test]
+fn visible_shows_newest_page() {
+    let mut log = MessageLog::new(10);
+    for message in ["one", "two", "three", "four"].iter() {
+        log.push(message);
+    }
+    assert_eq!(log.visible(2), vec!["three", "four"]);
+}
+
+#[test]
+fn scroll_up_stops_at_oldest_page() {
+    let mut log = MessageLog::new(10);
+    for message in ["one", "two", "three", "four"].iter() {
+        log.push(message);
+    }
+    for _ in 0..5 {
+        log.scroll_up(2);
+    }
+    assert_eq!(log.scroll, 2);
+    assert_eq!(log.visible(2), vec!["one", "two"]);
+    log.scroll_down();
+    assert_eq!(log.visible(2), vec!["two", "three"]);
+}
+
+#[test]
+fn push_scrolls_to_newest_message() {
+    let mut log = MessageLog::new(10);
+    for message in ["one", "two", "three"].iter() {
+        log.push(message);
+    }
+    log.scroll_up(1);
+    log.push("four");
+    assert_eq!(log.scroll, 0);
+    assert_eq!(log.visible(1), vec!["four"]);
+}
+
+#[test]
+fn log_shows_and_keeps_message() {
+    let mut ui = UI::new();
+    ui.log("Played 'Pair'".to_string());
+    assert_eq!(ui.message, "Played 'Pair'");
+    assert_eq!(ui.history.visible(1), vec!["Played 'Pair'"]);
+}
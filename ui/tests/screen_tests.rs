@@ -0,0 +1,122 @@
+extern crate ui;
+
+use ui::screen::{AnsiScreen,Input,Screen,BOLD,COLOR_RED,ERR,KEY_RESIZE,color_pair};
+
+/// Keys read in order, then no more keys
+struct ScriptedInput {
+    keys: Vec<i32>,
+    size: (i32, i32),
+}
+
+impl Input for ScriptedInput {
+
+    fn read_key(&mut self, _milliseconds: i32) -> i32 {
+        if self.keys.is_empty() {
+            return ERR
+        }
+        let key = self.keys.remove(0);
+        if key == KEY_RESIZE {
+            self.size = (self.size.0 + 1, self.size.1 + 1);
+        }
+        return key
+    }
+
+    fn size(&self) -> (i32, i32) {
+        return self.size
+    }
+
+    fn close(&mut self) {}
+}
+
+fn input(keys: Vec<i32>) -> ScriptedInput {
+    return ScriptedInput { keys: keys, size: (4, 10) }
+}
+
+#[test]
+fn refresh_writes_printed_text() {
+    let mut output = vec![];
+    {
+        let mut screen = AnsiScreen::new(&mut output, input(vec![]));
+        screen.mvprintw(1, 2, "hi");
+        screen.refresh();
+    }
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.starts_with("\x1b[?1049h\x1b[?25l\x1b[0m\x1b[2J"));
+    assert!(output.contains("\x1b[1;1H\x1b[0;39;49m"));
+    assert!(output.contains("            hi      "));
+}
+
+#[test]
+fn refresh_writes_only_changed_cells() {
+    let mut output = vec![];
+    {
+        let mut screen = AnsiScreen::new(&mut output, input(vec![]));
+        screen.mvprintw(0, 0, "abc");
+        screen.refresh();
+        screen.mvprintw(0, 0, "abd");
+        screen.refresh();
+    }
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.ends_with("\x1b[1;3H\x1b[0;39;49md\x1b[0m"));
+}
+
+#[test]
+fn refresh_writes_nothing_when_unchanged() {
+    let mut output = vec![];
+    {
+        let mut screen = AnsiScreen::new(&mut output, input(vec![]));
+        screen.refresh();
+        screen.refresh();
+    }
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.matches("\x1b[0m").count(), 2);
+}
+
+#[test]
+fn refresh_writes_colors_and_styles() {
+    let mut output = vec![];
+    {
+        let mut screen = AnsiScreen::new(&mut output, input(vec![]));
+        screen.init_pair(1, COLOR_RED, -1);
+        screen.attron(color_pair(1) | BOLD);
+        screen.mvprintw(0, 0, "x");
+        screen.refresh();
+    }
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("\x1b[0;1;31;49mx"));
+}
+
+#[test]
+fn printing_stops_at_end_of_screen() {
+    let mut output = vec![];
+    {
+        let mut screen = AnsiScreen::new(&mut output, input(vec![]));
+        screen.mvprintw(3, 8, "abcdef");
+        screen.mvprintw(4, 0, "hidden");
+        screen.refresh();
+    }
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("ab\x1b[0m"));
+    assert!(!output.contains("hidden"));
+}
+
+#[test]
+fn getch_resizes_screen() {
+    let mut output = vec![];
+    let mut screen = AnsiScreen::new(&mut output, input(vec![KEY_RESIZE, 'q' as i32]));
+    assert_eq!(screen.getch(), KEY_RESIZE);
+    assert_eq!((screen.lines(), screen.cols()), (5, 11));
+    assert_eq!(screen.getch(), 'q' as i32);
+    assert_eq!(screen.getch(), ERR);
+}
+
+#[test]
+fn end_restores_terminal() {
+    let mut output = vec![];
+    {
+        let mut screen = AnsiScreen::new(&mut output, input(vec![]));
+        screen.end();
+    }
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.ends_with("\x1b[0m\x1b[?25h\x1b[?1049l"));
+}